@@ -1,4 +1,5 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     tonic_build::compile_protos("proto/arima_connector.proto")?;
+    tonic_build::compile_protos("proto/signal_service.proto")?;
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
+use crate::signal::Signal;
 use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 
 pub struct ChainedInvestingStrategy<T1, T2, S1, S2>
 where
@@ -34,27 +35,43 @@ where
     S1: InvestingStrategy<T1>,
     S2: InvestingStrategy<T2>
 {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> (T1, T2) {
-        (self.strategy1.calculation(stock_price_info, yesterday), self.strategy2.calculation(stock_price_info, yesterday))
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, context: &StrategyContext) -> (T1, T2) {
+        (self.strategy1.calculation(stock_price_info, yesterday, context), self.strategy2.calculation(stock_price_info, yesterday, context))
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &(T1, T2)) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &(T1, T2)) -> Option<Signal> {
         let signal1 = self.strategy1.buy_signal(stock_price_info, &indicator.0);
         let signal2 = self.strategy2.buy_signal(stock_price_info, &indicator.1);
 
         match (signal1, signal2) {
-            (Some(price1), Some(price2)) => Some(f32::max(price1, price2)),
+            (Some(signal1), Some(signal2)) => Some(combine_signals(f32::max(signal1.price, signal2.price), &signal1, &signal2)),
             _ => None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &(T1, T2)) -> Option<f32> {
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &(T1, T2)) -> Option<Signal> {
         let signal1 = self.strategy1.sell_signal(stock_price_info, &indicator.0);
         let signal2 = self.strategy2.sell_signal(stock_price_info, &indicator.1);
 
         match (signal1, signal2) {
-            (Some(price1), Some(price2)) => Some(f32::min(price1, price2)),
+            (Some(signal1), Some(signal2)) => Some(combine_signals(f32::min(signal1.price, signal2.price), &signal1, &signal2)),
             _ => None
         }
     }
+
+    fn reset(&mut self) {
+        self.strategy1.reset();
+        self.strategy2.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(ChainedInvestingStrategy::new(self.strategy1.fork()?, self.strategy2.fork()?))
+    }
+}
+
+// Both strategies have to agree, so the chain is only as convinced as its weakest member.
+fn combine_signals(price: f32, signal1: &Signal, signal2: &Signal) -> Signal {
+    Signal::new(price,
+                f32::min(signal1.strength, signal2.strength),
+                format!("{}+{}", signal1.reason, signal2.reason).as_str())
 }
\ No newline at end of file
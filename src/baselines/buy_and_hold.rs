@@ -0,0 +1,27 @@
+use chrono::NaiveDate;
+use crate::broker_fee::BrokerFee;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+/// Value of buying as many shares as `invested_cash` allows at the first close on or after
+/// `start_date` and selling them at the last close.
+pub fn buy_and_hold(stock_data: &[StockPriceInfo],
+                    invested_cash: f32,
+                    start_date: NaiveDate,
+                    broker_fee: &dyn BrokerFee) -> f32 {
+    let mut window = stock_data.iter().filter(|day| day.date >= start_date);
+    let (Some(first_day), Some(last_day)) = (window.next(), stock_data.last()) else {
+        return invested_cash
+    };
+
+    let shares = affordable_volume(invested_cash, first_day.close, broker_fee);
+    let cash = invested_cash - shares as f32 * first_day.close - broker_fee.buy_fee(shares, first_day.close);
+    cash + shares as f32 * last_day.close - broker_fee.sell_fee(shares, last_day.close)
+}
+
+pub fn affordable_volume(cash: f32, price: f32, broker_fee: &dyn BrokerFee) -> usize {
+    let mut volume = (cash / price) as usize;
+    while volume > 0 && cash < volume as f32 * price + broker_fee.buy_fee(volume, price) {
+        volume -= 1;
+    }
+    volume
+}
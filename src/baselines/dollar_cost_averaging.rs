@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+use crate::baselines::buy_and_hold::affordable_volume;
+use crate::broker_fee::BrokerFee;
+use crate::stock_data_reader::resampler::Timeframe;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+/// Value of splitting `invested_cash` into equal contributions invested at the first close of
+/// every week or month from `start_date`, with all shares sold at the last close. Cash that does
+/// not buy a whole share is carried over to the next contribution.
+pub fn dollar_cost_averaging(stock_data: &[StockPriceInfo],
+                             invested_cash: f32,
+                             start_date: NaiveDate,
+                             period: Timeframe,
+                             broker_fee: &dyn BrokerFee) -> f32 {
+    let period_starts: Vec<&StockPriceInfo> = stock_data.iter()
+        .filter(|day| day.date >= start_date)
+        .fold(vec![], |mut period_starts: Vec<&StockPriceInfo>, day| {
+            if !period_starts.last().is_some_and(|start| period.same_period(start.date, day.date)) {
+                period_starts.push(day);
+            }
+            period_starts
+        });
+    let Some(last_day) = stock_data.last().filter(|_| !period_starts.is_empty()) else {
+        return invested_cash
+    };
+
+    let contribution = invested_cash / period_starts.len() as f32;
+    let mut available_cash = 0.0f32;
+    let mut shares = 0usize;
+    for day in period_starts {
+        available_cash += contribution;
+        let volume = affordable_volume(available_cash, day.close, broker_fee);
+        available_cash -= volume as f32 * day.close + broker_fee.buy_fee(volume, day.close);
+        shares += volume;
+    }
+    available_cash + shares as f32 * last_day.close - broker_fee.sell_fee(shares, last_day.close)
+}
@@ -0,0 +1,2 @@
+pub mod buy_and_hold;
+pub mod dollar_cost_averaging;
\ No newline at end of file
@@ -1,8 +1,12 @@
 pub trait BrokerFee {
     fn buy_fee(&self, shares: usize, price_per_share: f32) -> f32;
     fn sell_fee(&self, shares: usize, price_per_share: f32) -> f32;
+    /// Stateless fees have nothing to reset.
+    fn reset(&mut self) {}
+    fn fork(&self) -> Box<dyn BrokerFee>;
 }
 
+#[derive(Clone)]
 pub struct PricePercentageFee {
     percentage: f32
 }
@@ -23,5 +27,9 @@ impl BrokerFee for PricePercentageFee {
     fn sell_fee(&self, shares: usize, price_per_share: f32) -> f32 {
         price_per_share * shares as f32 * self.percentage
     }
+
+    fn fork(&self) -> Box<dyn BrokerFee> {
+        Box::new(self.clone())
+    }
 }
 
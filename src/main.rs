@@ -13,28 +13,66 @@ use rayon::prelude::*;
 use serde::Deserialize;
 use crate::brokage::brokage_stocks::get_available_stocks;
 
+use crate::baselines::buy_and_hold::buy_and_hold;
+use crate::baselines::dollar_cost_averaging::dollar_cost_averaging;
 use crate::broker_fee::PricePercentageFee;
 use crate::ChainedStrategy::ChainedInvestingStrategy;
 use crate::grid_search::grid_search::GridSearch;
 use crate::grid_search::parameter::Parameter;
+use crate::pairs_trading::cointegration::{screen_pairs, Significance};
+use crate::pairs_trading::pairs_trading_simulator::{PairsTradingConfig, PairsTradingSimulator};
+use crate::portfolio::momentum_rotation::{MomentumRotation, MomentumRotationConfig};
+use crate::portfolio::turtle_trading::{TurtleSizing, TurtleTrading};
+use crate::results_statistics::average_roi::average_return_of_investment;
+use crate::results_statistics::baseline_comparison::BaselineComparison;
 use crate::results_statistics::monte_carlo::monte_carlo_simulation;
+use crate::results_statistics::seasonality::seasonality_report;
 use crate::results_statistics::profitable_investment::number_of_profitable_investments;
+use crate::stock_data_reader::benchmark::Benchmark;
+use crate::stock_data_reader::resampler::Timeframe;
 use crate::stock_data_reader::stock_data_reader::{get_ticker_files, read_from_file, StockPriceInfo};
 use crate::stop_loss_strategy::{NoStopLoss, PercentageStopLoss};
+use crate::strategies::adx_strategy::AdxStrategy;
+use crate::strategies::adx_trend_filter::AdxTrendFilter;
 use crate::strategies::arima::ArimaStrategy;
+use crate::strategies::aroon_strategy::AroonStrategy;
+use crate::strategies::cci_strategy::CciStrategy;
+use crate::strategies::bollinger_mean_reversion_strategy::BollingerMeanReversionStrategy;
+use crate::strategies::bollinger_squeeze_strategy::BollingerSqueezeStrategy;
 use crate::strategies::ema_crossover_strategy::EmaCrossoverStrategy;
 use crate::strategies::ema_long_term_trend::EmaLongTermTrendStrategy;
 use crate::strategies::growing_ema_investing_strategy::GrowingEmaStrategy;
-use crate::strategy_simulator::StrategySimulator;
+use crate::strategies::indicator_crossover_strategy::IndicatorCrossoverStrategy;
+use crate::strategies::indicator_threshold_strategy::IndicatorThresholdStrategy;
+use crate::strategies::ichimoku_strategy::{IchimokuRule, IchimokuStrategy};
+use crate::strategy_simulator::{ForkableStrategy, StrategySimulator};
 use crate::strategy_simulator::TradeResult::{Buy, Sell, StopLoss, TakeProfit};
 use crate::technical_indicator::keltner_channel::KeltnerChannel;
+use crate::technical_indicator::percent_off_ath::PercentOffAth;
 use crate::strategies::macd_divergence_strategy::MACDDivergenceStrategy;
 use crate::strategies::macd_strategy::MACDStrategy;
+use crate::strategies::market_regime_filter::MarketRegimeFilter;
+use crate::strategies::mfi_strategy::MfiStrategy;
+use crate::strategies::parabolic_sar_strategy::ParabolicSarStrategy;
 use crate::strategies::rsi_strategy::RsiStrategy;
+use crate::strategies::stochastic_strategy::StochasticStrategy;
+use crate::strategies::supertrend_strategy::SupertrendStrategy;
+use crate::strategies::turtle_strategy::TurtleSystem;
+use crate::strategies::volume_confirmation::VolumeConfirmation;
+use crate::strategies::wasm_plugin_strategy::{WasmPlugin, WasmPluginLimits};
+use crate::strategies::williams_r_strategy::WilliamsRStrategy;
 use crate::take_profit_strategy::{NoTakeProfit, PercentageTakeProfit};
-use crate::technical_indicator::ema::Ema;
+use crate::technical_indicator::chained::Chained;
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::ichimoku::Ichimoku;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::technical_indicator::indicator_cache::IndicatorCache;
 use crate::technical_indicator::macd::Macd;
-use crate::technical_indicator::percent_off_ath::PercentOffAth;
+use crate::technical_indicator::obv::Obv;
+use crate::technical_indicator::price_source::{PriceSource, Sourced};
+use crate::technical_indicator::relative_strength::RelativeStrength;
+use crate::technical_indicator::rsi::Rsi;
+use crate::technical_indicator::stochastic::Stochastic;
 
 
 mod strategy_simulator;
@@ -50,9 +88,13 @@ mod grid_search;
 mod stock_data_reader;
 mod take_profit_strategy;
 mod ChainedStrategy;
+mod signal;
+mod pairs_trading;
+mod portfolio;
+mod baselines;
 
 fn simulate_ticker(stock_data: &Vec<StockPriceInfo>,
-                    buy_ema_length: usize,
+                    buy_ema: Arc<Vec<f32>>,
                     sell_ema_length: usize,
                     buy_inclination: f32,
                     sell_inclination: f32,
@@ -61,7 +103,7 @@ fn simulate_ticker(stock_data: &Vec<StockPriceInfo>,
      let mut strategy =
          StrategySimulator::new(10000.0f32,
                                 NaiveDate::from_ymd(2019, 11, 1),
-                                Box::new(EmaLongTermTrendStrategy::new(buy_ema_length, buy_inclination, sell_inclination)), // 20.0, -10.0
+                                Box::new(EmaLongTermTrendStrategy::with_precomputed_ema(buy_ema, buy_inclination, sell_inclination)), // 20.0, -10.0
                                 Box::new(NoTakeProfit),
                                 Box::new(PercentageStopLoss::new(stop_loss_param)),
                                 Box::new(PricePercentageFee::new(0.0035)));
@@ -87,25 +129,35 @@ fn simulate_ticker(stock_data: &Vec<StockPriceInfo>,
  }
 
 
-fn generate_indicator_data(file_path: &Path) {
+fn generate_indicator_data<I: Indicator>(file_path: &Path, mut technical_indicator: I, indicator_name: &str) {
     let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
     println!("Generating data for {}", file_name_str);
-    let mut stock_data = read_from_file(file_path);
+    let stock_data = read_from_file(file_path);
 
-    let mut technical_indicator = PercentOffAth::new();
     let mut data: Vec<(NaiveDate, Vec<f32>)> = vec![];
     for day in stock_data.iter() {
-        data.push((day.date, vec![technical_indicator.next(day.high)]))
+        data.push((day.date, technical_indicator.update(day).to_values()))
     }
 
-    data.save_to_csv(format!("ticker_data/{}_keltner.csv", file_name_str).as_str()).unwrap()
+    data.save_to_csv(format!("ticker_data/{}_{}.csv", file_name_str, indicator_name).as_str()).unwrap()
 }
 
-fn process_ticker(file_path: &Path, start_date: NaiveDate) -> io::Result<f32> {
+fn generate_seasonality_report(file_path: &Path) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    println!("Generating seasonality report for {}", file_name_str);
+    let stock_data = read_from_file(file_path);
+
+    let report = seasonality_report(&stock_data);
+    report.by_weekday.save_to_csv(format!("ticker_data/seasonality/{}_weekday.csv", file_name_str).as_str()).unwrap();
+    report.by_month.save_to_csv(format!("ticker_data/seasonality/{}_month.csv", file_name_str).as_str()).unwrap()
+}
+
+fn process_ticker(file_path: &Path, start_date: NaiveDate) -> io::Result<(f32, BaselineComparison)> {
     let mut cash_after_last_sell: f32 = 0.0;
     let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
     println!("Simulating strategy for {}", file_name_str);
     let mut stock_data = read_from_file(file_path);
+    let broker_fee = PricePercentageFee::new(0.0035);
 
 
     let mut keltner_channel_simulator =
@@ -114,7 +166,7 @@ fn process_ticker(file_path: &Path, start_date: NaiveDate) -> io::Result<f32> {
                                Box::new(ChainedInvestingStrategy::new(KeltnerChannel::new(20, 3.0), EmaCrossoverStrategy::new(20, 50))),
                                Box::new(NoTakeProfit),
                                Box::new(PercentageStopLoss::new(0.5)),
-                               Box::new(PricePercentageFee::new(0.0035)));
+                               Box::new(broker_fee.clone()));
 
     let mut buy_operation = vec![];
     let mut sell_operation = vec![];
@@ -129,9 +181,9 @@ fn process_ticker(file_path: &Path, start_date: NaiveDate) -> io::Result<f32> {
         strategy_results.push((result.operation_date, result.strategy_params.0.today.into()));
         for operation_performed in result.trade_operations {
             match operation_performed {
-                Buy(buy_trade) => buy_operation.push((result.operation_date, vec![buy_trade.price])),
+                Buy(buy_trade) => buy_operation.push((result.operation_date, vec![buy_trade.price, buy_trade.strength])),
                 Sell(sell_trade) => {
-                    sell_operation.push((result.operation_date, vec![sell_trade.price]));
+                    sell_operation.push((result.operation_date, vec![sell_trade.price, sell_trade.strength]));
                     cash_after_last_sell = sell_trade.after_operation_cash
                 }
                 StopLoss(stop_loss_trade) => {
@@ -152,11 +204,18 @@ fn process_ticker(file_path: &Path, start_date: NaiveDate) -> io::Result<f32> {
     stop_loss_operation.save_to_csv(format!("ticker_data/signals/{}_keltner_stop_loss_signal.csv", file_name_str).as_str());
     take_profit_operation.save_to_csv(format!("ticker_data/signals/{}_keltner_take_profit.csv", file_name_str).as_str());
     strategy_results.save_to_csv(format!("ticker_data/{}_keltner.csv", file_name_str).as_str());
-    Ok(cash_after_last_sell)
+
+    let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+    let baseline_comparison = BaselineComparison {
+        strategy: keltner_channel_simulator.liquidation_value(last_close),
+        buy_and_hold: buy_and_hold(&stock_data, 10000.0f32, start_date, &broker_fee),
+        dollar_cost_averaging: dollar_cost_averaging(&stock_data, 10000.0f32, start_date, Timeframe::Monthly, &broker_fee)
+    };
+    Ok((cash_after_last_sell, baseline_comparison))
 }
 
-fn process_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate) -> HashMap<String, f32> {
-    let mut result_map: Arc<Mutex<HashMap<String, f32>>> = Arc::new(Mutex::new(HashMap::new()));
+fn process_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate) -> HashMap<String, (f32, BaselineComparison)> {
+    let result_map: Arc<Mutex<HashMap<String, (f32, BaselineComparison)>>> = Arc::new(Mutex::new(HashMap::new()));
     let files = get_ticker_files(dir_path, brokage_house);
 
     files.par_iter().for_each(|filepath| {
@@ -176,11 +235,309 @@ fn process_directory_data_generation(dir_path: &Path, brokage_house: &str) {
     let files = get_ticker_files(dir_path, brokage_house);
 
     files.par_iter().for_each(|filepath| {
-        generate_indicator_data(filepath)
+        generate_indicator_data(filepath, PercentOffAth::new(), "keltner")
+    })
+}
+
+fn process_directory_seasonality(dir_path: &Path, brokage_house: &str) {
+    let files = get_ticker_files(dir_path, brokage_house);
+
+    files.par_iter().for_each(|filepath| {
+        generate_seasonality_report(filepath)
     })
 }
 
+fn screen_pairs_in_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate) {
+    let files = get_ticker_files(dir_path, brokage_house);
+    let loaded_files: HashMap<String, Vec<StockPriceInfo>> =
+        files.par_iter()
+            .map(|file_path| (file_path.file_name().unwrap().to_str().unwrap().to_ascii_lowercase(), read_from_file(file_path)))
+            .collect();
+
+    for (pair, cointegration) in screen_pairs(&loaded_files, start_date, 1, Significance::P05).iter().take(20) {
+        let mut simulator = PairsTradingSimulator::new(10000.0f32,
+                                                       start_date,
+                                                       PairsTradingConfig {
+                                                           hedge_ratio_window: 60,
+                                                           z_score_window: 20,
+                                                           entry_z_score: 2.0,
+                                                           exit_z_score: 0.5
+                                                       },
+                                                       Box::new(PricePercentageFee::new(0.0035)));
+        let result = simulator.run(pair);
+        println!("Pair: {} / {} - ADF: {:.2}, hedge ratio: {:.3}, trades: {}, equity: {}",
+                 pair.first_ticker,
+                 pair.second_ticker,
+                 cointegration.adf_statistic,
+                 cointegration.hedge_ratio,
+                 result.leg_trades.len(),
+                 result.equity_curve.last().map(|(_, equity)| *equity).unwrap_or(0.0));
+    }
+}
+
+/// Same Keltner/EMA crossover strategy as `process_ticker`, once as is and once behind a market
+/// regime filter, to see what buying only above the benchmark's 200-day EMA changes.
+fn market_regime_filter_in_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate, benchmark_file: &Path) -> Result<(), Box<dyn Error>> {
+    let files = get_ticker_files(dir_path, brokage_house);
+    let benchmark = Arc::new(Benchmark::from_file(benchmark_file)?);
+
+    files.par_iter().for_each(|filepath| {
+        let file_name_str = filepath.file_name().unwrap().to_str().unwrap();
+        let stock_data = read_from_file(filepath);
+        let mut unfiltered = StrategySimulator::new(10000.0f32,
+                                                    start_date,
+                                                    Box::new(ChainedInvestingStrategy::new(KeltnerChannel::new(20, 3.0), EmaCrossoverStrategy::new(20, 50))),
+                                                    Box::new(NoTakeProfit),
+                                                    Box::new(PercentageStopLoss::new(0.5)),
+                                                    Box::new(PricePercentageFee::new(0.0035)));
+        let mut filtered = StrategySimulator::new(10000.0f32,
+                                                  start_date,
+                                                  Box::new(MarketRegimeFilter::new(ChainedInvestingStrategy::new(KeltnerChannel::new(20, 3.0), EmaCrossoverStrategy::new(20, 50)), 200)),
+                                                  Box::new(NoTakeProfit),
+                                                  Box::new(PercentageStopLoss::new(0.5)),
+                                                  Box::new(PricePercentageFee::new(0.0035)))
+            .with_benchmark(benchmark.clone());
+
+        let mut previous_date: Option<StockPriceInfo> = None;
+        for day in stock_data.iter() {
+            unfiltered.next(day, &previous_date);
+            filtered.next(day, &previous_date);
+            previous_date = Some(day.clone())
+        }
+        let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+        println!("{}: equity {} unfiltered, {} with market regime filter",
+                 file_name_str, unfiltered.equity(last_close), filtered.equity(last_close));
+    });
+    Ok(())
+}
+
+fn momentum_rotation_in_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate) {
+    let files = get_ticker_files(dir_path, brokage_house);
+    let loaded_files: HashMap<String, Vec<StockPriceInfo>> =
+        files.par_iter()
+            .map(|file_path| (file_path.file_name().unwrap().to_str().unwrap().to_ascii_lowercase(), read_from_file(file_path)))
+            .collect();
+
+    let rotation = MomentumRotation::new(10000.0f32,
+                                         start_date,
+                                         MomentumRotationConfig {
+                                             lookback_months: 12,
+                                             skip_months: 1,
+                                             top_k: 10,
+                                             rebalance_every_months: 1
+                                         },
+                                         Box::new(PricePercentageFee::new(0.0035)));
+    let result = rotation.run(&loaded_files);
+    println!("Final equity: {}", result.equity_curve.last().map(|(_, equity)| *equity).unwrap_or(0.0));
+    println!("Rebalances: {}, average turnover: {:.3}, fees paid: {:.2}",
+             result.rebalances.len(), result.average_turnover(), result.total_fees());
+
+    let equity_curve: Vec<(NaiveDate, Vec<f32>)> = result.equity_curve.iter()
+        .map(|(date, equity)| (*date, vec![*equity]))
+        .collect();
+    equity_curve.save_to_csv("momentum_rotation_equity.csv").unwrap()
+}
+
+fn wasm_plugin_in_directory(dir_path: &Path, brokage_house: &str, start_date: NaiveDate, plugin_path: &Path) -> Result<(), Box<dyn Error>> {
+    let files = get_ticker_files(dir_path, brokage_house);
+    let plugin = WasmPlugin::from_file(plugin_path, WasmPluginLimits::default())?;
+
+    files.par_iter().try_for_each(|filepath| {
+        let file_name_str = filepath.file_name().unwrap().to_str().unwrap();
+        let stock_data = read_from_file(filepath);
+        let strategy = plugin.instantiate().map_err(|error| format!("{}: {}", file_name_str, error))?;
+        let mut simulator = StrategySimulator::new(10000.0f32,
+                                                   start_date,
+                                                   Box::new(strategy),
+                                                   Box::new(NoTakeProfit),
+                                                   Box::new(PercentageStopLoss::new(0.5)),
+                                                   Box::new(PricePercentageFee::new(0.0035)));
+        for day in stock_data.iter() {
+            simulator.next_today(day);
+        }
+        let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+        println!("{}: equity {}", file_name_str, simulator.equity(last_close));
+        Ok::<(), String>(())
+    })?;
+    Ok(())
+}
+
+fn what_if_stop_loss(file_path: &Path, start_date: NaiveDate, fork_date: NaiveDate, stop_loss_percentages: &[f32]) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+    let mut simulator = StrategySimulator::new(10000.0f32,
+                                               start_date,
+                                               Box::new(ChainedInvestingStrategy::new(KeltnerChannel::new(20, 3.0), EmaCrossoverStrategy::new(20, 50))),
+                                               Box::new(NoTakeProfit),
+                                               Box::new(PercentageStopLoss::new(0.5)),
+                                               Box::new(PricePercentageFee::new(0.0035)));
+    let (history, remaining) = stock_data.split_at(stock_data.partition_point(|day| day.date < fork_date));
+    for day in history.iter() {
+        simulator.next_today(day);
+    }
+
+    let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+    for stop_loss_percentage in stop_loss_percentages {
+        let mut branch = simulator.fork()
+            .expect("Strategy cannot be forked")
+            .with_stop_loss(Box::new(PercentageStopLoss::new(*stop_loss_percentage)));
+        for day in remaining.iter() {
+            branch.next_today(day);
+        }
+        println!("{}: stop loss {} from {} => equity {}", file_name_str, stop_loss_percentage, fork_date, branch.equity(last_close));
+    }
+}
+
+fn compare_turtle_with_ema_crossover(file_path: &Path, start_date: NaiveDate) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+    let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+
+    let mut ema_crossover = StrategySimulator::new(10000.0f32,
+                                                   start_date,
+                                                   Box::new(EmaCrossoverStrategy::new(20, 50)),
+                                                   Box::new(NoTakeProfit),
+                                                   Box::new(NoStopLoss),
+                                                   Box::new(PricePercentageFee::new(0.0035)));
+    for day in stock_data.iter() {
+        ema_crossover.next_today(day);
+    }
+    println!("{}: EMA crossover equity {}", file_name_str, ema_crossover.equity(last_close));
+
+    for (name, system) in [("System 1", TurtleSystem::system_1()), ("System 2", TurtleSystem::system_2())] {
+        let turtle = TurtleTrading::new(10000.0f32, start_date, system, TurtleSizing::default(), Box::new(PricePercentageFee::new(0.0035)));
+        let result = turtle.run(&stock_data);
+        println!("{}: turtle {} equity {}, trades: {}",
+                 file_name_str,
+                 name,
+                 result.equity_curve.last().map(|(_, equity)| *equity).unwrap_or(0.0),
+                 result.trades.len());
+    }
+}
+
+fn compare_bollinger_strategies(file_path: &Path, start_date: NaiveDate) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+    let last_close = stock_data.last().map(|day| day.close).unwrap_or(0.0);
+
+    let mut mean_reversion = StrategySimulator::new(10000.0f32,
+                                                    start_date,
+                                                    Box::new(BollingerMeanReversionStrategy::new(20, 2.0, 0.5)),
+                                                    Box::new(NoTakeProfit),
+                                                    Box::new(NoStopLoss),
+                                                    Box::new(PricePercentageFee::new(0.0035)));
+    let mut squeeze = StrategySimulator::new(10000.0f32,
+                                             start_date,
+                                             Box::new(BollingerSqueezeStrategy::new(20, 2.0, 125, 10)),
+                                             Box::new(NoTakeProfit),
+                                             Box::new(NoStopLoss),
+                                             Box::new(PricePercentageFee::new(0.0035)));
+    let mut keltner = StrategySimulator::new(10000.0f32,
+                                             start_date,
+                                             Box::new(KeltnerChannel::new(20, 2.0)),
+                                             Box::new(NoTakeProfit),
+                                             Box::new(NoStopLoss),
+                                             Box::new(PricePercentageFee::new(0.0035)));
+    for day in stock_data.iter() {
+        mean_reversion.next_today(day);
+        squeeze.next_today(day);
+        keltner.next_today(day);
+    }
+    println!("{}: Bollinger mean reversion equity {}, Bollinger squeeze equity {}, Keltner channel equity {}",
+             file_name_str,
+             mean_reversion.equity(last_close),
+             squeeze.equity(last_close),
+             keltner.equity(last_close));
+}
+
+fn final_equity<T: Clone>(stock_data: &[StockPriceInfo], start_date: NaiveDate, strategy: Box<dyn ForkableStrategy<T>>) -> f32 {
+    let mut simulator = StrategySimulator::new(10000.0f32,
+                                               start_date,
+                                               strategy,
+                                               Box::new(NoTakeProfit),
+                                               Box::new(NoStopLoss),
+                                               Box::new(PricePercentageFee::new(0.0035)));
+    for day in stock_data.iter() {
+        simulator.next_today(day);
+    }
+    simulator.equity(stock_data.last().map(|day| day.close).unwrap_or(0.0))
+}
+
+fn compare_oscillator_strategies(file_path: &Path, start_date: NaiveDate) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+
+    let results = [
+        ("RSI", final_equity(&stock_data, start_date, Box::new(RsiStrategy::new(14, 30.0, 70.0)))),
+        ("fast stochastic", final_equity(&stock_data, start_date, Box::new(StochasticStrategy::new(Stochastic::fast(14, 3), 20.0, 80.0)))),
+        ("slow stochastic", final_equity(&stock_data, start_date, Box::new(StochasticStrategy::new(Stochastic::slow(14, 3), 20.0, 80.0)))),
+        ("Williams %R", final_equity(&stock_data, start_date, Box::new(WilliamsRStrategy::new(14, -80.0, -20.0)))),
+        ("CCI", final_equity(&stock_data, start_date, Box::new(CciStrategy::new(20, -100.0, 100.0)))),
+        ("MFI", final_equity(&stock_data, start_date, Box::new(MfiStrategy::new(14, 20.0, 80.0))))
+    ];
+    for (name, equity) in results {
+        println!("{}: {} equity {}", file_name_str, name, equity);
+    }
+}
+
+fn compare_trend_strategies(file_path: &Path, start_date: NaiveDate) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+
+    let results = [
+        ("EMA crossover", final_equity(&stock_data, start_date, Box::new(EmaCrossoverStrategy::new(20, 50)))),
+        ("EMA crossover with ADX filter", final_equity(&stock_data, start_date, Box::new(AdxTrendFilter::new(EmaCrossoverStrategy::new(20, 50), 14, 25.0)))),
+        ("ADX", final_equity(&stock_data, start_date, Box::new(AdxStrategy::new(14, 20.0)))),
+        ("Aroon", final_equity(&stock_data, start_date, Box::new(AroonStrategy::new(25, 70.0)))),
+        ("Parabolic SAR", final_equity(&stock_data, start_date, Box::new(ParabolicSarStrategy::new(0.02, 0.2)))),
+        ("Supertrend", final_equity(&stock_data, start_date, Box::new(SupertrendStrategy::new(10, 3.0)))),
+        ("Ichimoku cloud breakout", final_equity(&stock_data, start_date, Box::new(IchimokuStrategy::new(Ichimoku::standard(), IchimokuRule::CloudBreakout)))),
+        ("Ichimoku TK cross", final_equity(&stock_data, start_date, Box::new(IchimokuStrategy::new(Ichimoku::standard(), IchimokuRule::TkCross))))
+    ];
+    for (name, equity) in results {
+        println!("{}: {} equity {}", file_name_str, name, equity);
+    }
+}
+
+fn compare_volume_confirmation(file_path: &Path, start_date: NaiveDate) {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+
+    let results = [
+        ("EMA crossover", final_equity(&stock_data, start_date, Box::new(EmaCrossoverStrategy::new(20, 50)))),
+        ("EMA crossover on above-average volume", final_equity(&stock_data, start_date, Box::new(VolumeConfirmation::above_average(EmaCrossoverStrategy::new(20, 50), 20)))),
+        ("Bollinger squeeze", final_equity(&stock_data, start_date, Box::new(BollingerSqueezeStrategy::new(20, 2.0, 125, 10)))),
+        ("Bollinger squeeze on 1.5x volume", final_equity(&stock_data, start_date, Box::new(VolumeConfirmation::new(BollingerSqueezeStrategy::new(20, 2.0, 125, 10), 20, 1.5))))
+    ];
+    for (name, equity) in results {
+        println!("{}: {} equity {}", file_name_str, name, equity);
+    }
+}
+
+fn compare_chained_indicator_strategies(file_path: &Path, start_date: NaiveDate, benchmark_file: &Path) -> Result<(), Box<dyn Error>> {
+    let file_name_str = file_path.file_name().unwrap().to_str().unwrap();
+    let stock_data = read_from_file(file_path);
+    let benchmark = Arc::new(Benchmark::from_file(benchmark_file)?);
+
+    let results = [
+        ("RSI on hlc3", final_equity(&stock_data, start_date, Box::new(RsiStrategy::with_source(14, 30.0, 70.0, PriceSource::Hlc3)))),
+        ("EMA of RSI", final_equity(&stock_data, start_date, Box::new(IndicatorThresholdStrategy::new(Chained::new(Rsi::new(14), Ema::with_seed(9, EmaSeed::Sma)), 35.0, 65.0, 30.0, "ema_of_rsi")))),
+        ("RSI of OBV", final_equity(&stock_data, start_date, Box::new(IndicatorThresholdStrategy::new(Chained::new(Obv::new(), Rsi::new(14)), 30.0, 70.0, 30.0, "rsi_of_obv")))),
+        ("EMA crossover on hl2", final_equity(&stock_data, start_date, Box::new(IndicatorCrossoverStrategy::relative(Sourced::new(PriceSource::Hl2, Ema::with_seed(20, EmaSeed::Sma)), Sourced::new(PriceSource::Hl2, Ema::with_seed(50, EmaSeed::Sma)), 0.05)))),
+        ("relative strength EMA crossover", final_equity(&stock_data, start_date, Box::new(IndicatorCrossoverStrategy::relative(
+            Chained::new(RelativeStrength::new(benchmark.clone(), PriceSource::Close), Ema::with_seed(12, EmaSeed::Sma)),
+            Chained::new(RelativeStrength::new(benchmark.clone(), PriceSource::Close), Ema::with_seed(26, EmaSeed::Sma)),
+            0.05))))
+    ];
+    for (name, equity) in results {
+        println!("{}: {} equity {}", file_name_str, name, equity);
+    }
+    generate_indicator_data(file_path, Chained::new(RelativeStrength::new(benchmark, PriceSource::Close), Macd::with_seed(26, 12, 9, EmaSeed::Sma)), "macd_of_relative_strength");
+    Ok(())
+}
+
 fn process_directory_v2(data: &HashMap<String, Vec<StockPriceInfo>>,
+                           indicator_cache: &IndicatorCache,
                            buy_ema_length: usize,
                            sell_ema_length: usize,
                            buy_inclination: f32,
@@ -189,8 +546,9 @@ fn process_directory_v2(data: &HashMap<String, Vec<StockPriceInfo>>,
     let mut result_map: Arc<Mutex<HashMap<String, f32>>> = Arc::new(Mutex::new(HashMap::new()));
 
     data.par_iter().for_each(|(file_name, stock_data)| {
+        let buy_ema = indicator_cache.column(file_name, Ema::new(buy_ema_length), stock_data);
         let result = simulate_ticker(
-            stock_data, buy_ema_length, sell_ema_length, buy_inclination, sell_inclination, stop_loss_param);
+            stock_data, buy_ema, sell_ema_length, buy_inclination, sell_inclination, stop_loss_param);
         let mut map_unlocked = result_map.lock().unwrap();
         map_unlocked.insert(file_name.clone(), result);
     });
@@ -211,12 +569,33 @@ fn main() -> Result<(), Box<dyn Error>> {
     let start = Instant::now();
     //grid_search_growing_ema();
     //process_directory_data_generation(Path::new("nasdaq"), "XTB");
+    //process_directory_seasonality(Path::new("nasdaq"), "XTB");
+    //screen_pairs_in_directory(Path::new("nasdaq"), "XTB", NaiveDate::from_ymd(2019, 11, 1));
+    //market_regime_filter_in_directory(Path::new("nasdaq"), "XTB", NaiveDate::from_ymd(2019, 11, 1), Path::new("nasdaq/qqq.us.txt"))?;
+    //momentum_rotation_in_directory(Path::new("nasdaq"), "XTB", NaiveDate::from_ymd(2019, 11, 1));
+    //wasm_plugin_in_directory(Path::new("nasdaq"), "XTB", NaiveDate::from_ymd(2019, 11, 1), Path::new("plugins/strategy.wasm"))?;
+    //compare_turtle_with_ema_crossover(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1));
+    //what_if_stop_loss(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1), NaiveDate::from_ymd(2022, 1, 3), &[0.05, 0.1, 0.2]);
+    //compare_bollinger_strategies(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1));
+    //compare_oscillator_strategies(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1));
+    //compare_trend_strategies(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1));
+    //compare_volume_confirmation(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1));
+    //compare_chained_indicator_strategies(Path::new("nasdaq/aapl.us.txt"), NaiveDate::from_ymd(2019, 11, 1), Path::new("nasdaq/qqq.us.txt"))?;
 
     let map = process_directory(Path::new("nasdaq"), "XTB", NaiveDate::from_ymd(2019, 11, 1));
-    let mut vec_tuple: Vec<(String, f32)> = map.into_iter().collect();
+    let baseline_comparisons: Vec<BaselineComparison> = map.values().map(|(_, comparison)| *comparison).collect();
+    let mut vec_tuple: Vec<(String, f32)> = map.iter().map(|(ticker, (cash, _))| (ticker.clone(), *cash)).collect();
     vec_tuple.sort_by(|a,b| b.1.partial_cmp(&a.1).unwrap());
     for (ticker, accumulated_cash) in vec_tuple.iter() {
-        println!("Ticker: {} - {}", ticker, accumulated_cash)
+        let comparison = map[ticker].1;
+        println!("Ticker: {} - {} (equity: {:.2}, buy and hold: {:.2} [{:+.2}%], DCA: {:.2} [{:+.2}%])",
+                 ticker,
+                 accumulated_cash,
+                 comparison.strategy,
+                 comparison.buy_and_hold,
+                 comparison.excess_over_buy_and_hold(),
+                 comparison.dollar_cost_averaging,
+                 comparison.excess_over_dollar_cost_averaging())
     }
     let gained_cash = vec_tuple.iter().filter(|&value| value.1 > 10000.0).count();
     let no_data = vec_tuple.iter().filter(|&value| value.1 == 0.0).count();
@@ -224,6 +603,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("Cash gained in {} tickers", gained_cash);
     println!("Cash lost in {} tickers", lost_cash);
     println!("No buy/sell operation in {} tickers", no_data);
+    let beat_buy_and_hold = baseline_comparisons.iter().filter(|comparison| comparison.strategy > comparison.buy_and_hold).count();
+    let beat_dca = baseline_comparisons.iter().filter(|comparison| comparison.strategy > comparison.dollar_cost_averaging).count();
+    println!("Strategy beat buy and hold in {} tickers, average excess return: {:+.2}%",
+             beat_buy_and_hold,
+             average_return_of_investment(baseline_comparisons.iter().map(|comparison| comparison.excess_over_buy_and_hold()).collect()));
+    println!("Strategy beat DCA in {} tickers, average excess return: {:+.2}%",
+             beat_dca,
+             average_return_of_investment(baseline_comparisons.iter().map(|comparison| comparison.excess_over_dollar_cost_averaging()).collect()));
     let ROIs: Vec<f32> = vec_tuple.iter().map(|x| x.1).collect();
     let monte_carlo_result = monte_carlo_simulation(ROIs, 20000, 5);
     monte_carlo_result.save_to_csv("monte_carlo_simulation.csv");
@@ -248,6 +635,8 @@ fn grid_search_growing_ema() -> f32 {
         files.par_iter()
             .map(|file_path| (file_path.file_name().unwrap().to_str().unwrap().to_ascii_lowercase(), read_from_file(file_path)))
             .collect();
+    // Only the EMA length changes the EMA, the other parameters reuse the columns already computed.
+    let indicator_cache = IndicatorCache::new();
 
     let strategy = |params: &[f32]| -> f32 {
         let buy_ema_length: usize = params[0] as usize;
@@ -257,6 +646,7 @@ fn grid_search_growing_ema() -> f32 {
         let stop_loss_param: f32 = params[4];
 
         let res = process_directory_v2(&loaded_files,
+                                       &indicator_cache,
                                        buy_ema_length,
                                        sell_ema_length,
                                        buy_inclination,
@@ -267,6 +657,8 @@ fn grid_search_growing_ema() -> f32 {
 
     println!("Starting grid search");
     let results = search.search(strategy);
+    println!("Computed {} indicator series for {} lookups",
+             indicator_cache.computations(), indicator_cache.lookups());
     results.save_to_csv("growing_ema_grid_search.csv");
     0.0
 
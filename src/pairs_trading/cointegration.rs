@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use chrono::NaiveDate;
+use itertools::Itertools;
+use rayon::prelude::*;
+use crate::pairs_trading::least_squares::least_squares;
+use crate::pairs_trading::pair_data::PairData;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Significance {
+    /// 1% significance level.
+    P01,
+    /// 5% significance level.
+    P05,
+    /// 10% significance level.
+    P10
+}
+
+impl Significance {
+    // MacKinnon (2010) asymptotic critical values of the Engle-Granger test for two variables with a constant.
+    fn engle_granger_critical_value(&self) -> f64 {
+        match self {
+            Significance::P01 => -3.90,
+            Significance::P05 => -3.34,
+            Significance::P10 => -3.04
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CointegrationResult {
+    pub intercept: f64,
+    pub hedge_ratio: f64,
+    pub adf_statistic: f64,
+    pub observations: usize
+}
+
+impl CointegrationResult {
+    pub fn is_cointegrated(&self, significance: Significance) -> bool {
+        self.adf_statistic < significance.engle_granger_critical_value()
+    }
+}
+
+/// Engle-Granger two step test: regresses the first leg on the second and runs an augmented
+/// Dickey-Fuller test with `adf_lags` lagged differences on the residual spread.
+pub fn engle_granger(pair: &PairData, adf_lags: usize) -> Option<CointegrationResult> {
+    let (first, second) = pair.closes();
+    let regressors: Vec<Vec<f64>> = second.iter().map(|&x| vec![1.0, x]).collect();
+    let cointegrating_regression = least_squares(&regressors, &first)?;
+
+    Some(CointegrationResult {
+        intercept: cointegrating_regression.coefficients[0],
+        hedge_ratio: cointegrating_regression.coefficients[1],
+        adf_statistic: augmented_dickey_fuller(&cointegrating_regression.residuals, adf_lags)?,
+        observations: first.len()
+    })
+}
+
+/// t-statistic of `gamma` in `d(e_t) = gamma * e_(t-1) + sum(phi_i * d(e_(t-i)))`, without a constant
+/// because regression residuals already have zero mean.
+pub fn augmented_dickey_fuller(series: &[f64], lags: usize) -> Option<f64> {
+    let differences: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let (regressors, dependent): (Vec<Vec<f64>>, Vec<f64>) = (lags..differences.len())
+        .map(|t| {
+            let mut row = vec![series[t]];
+            row.extend((1..=lags).map(|lag| differences[t - lag]));
+            (row, differences[t])
+        })
+        .unzip();
+
+    let fit = least_squares(&regressors, &dependent)?;
+    Some(fit.coefficients[0] / fit.standard_errors[0])
+}
+
+/// Runs the Engle-Granger test on every pair of the given tickers and returns the cointegrated
+/// ones, most significant first. Only the bars before `formation_end` are tested, so that pairs
+/// traded from that date on are not selected with prices from the trading window. The returned
+/// pairs still hold the whole history.
+pub fn screen_pairs(stock_data: &HashMap<String, Vec<StockPriceInfo>>,
+                    formation_end: NaiveDate,
+                    adf_lags: usize,
+                    significance: Significance) -> Vec<(PairData, CointegrationResult)> {
+    let tickers: Vec<&String> = stock_data.keys().sorted().collect();
+    let candidates: Vec<(&String, &String)> = tickers.iter().cloned().tuple_combinations().collect();
+
+    let mut cointegrated: Vec<(PairData, CointegrationResult)> = candidates.par_iter()
+        .map(|&(first, second)| PairData::new(first, &stock_data[first], second, &stock_data[second]))
+        .filter_map(|pair| engle_granger(&pair.before(formation_end), adf_lags).map(|result| (pair, result)))
+        .filter(|(_, result)| result.is_cointegrated(significance))
+        .collect();
+
+    cointegrated.sort_by(|a, b| a.1.adf_statistic.total_cmp(&b.1.adf_statistic));
+    cointegrated
+}
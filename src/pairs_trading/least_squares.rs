@@ -0,0 +1,79 @@
+pub struct LeastSquaresFit {
+    pub coefficients: Vec<f64>,
+    pub standard_errors: Vec<f64>,
+    pub residuals: Vec<f64>
+}
+
+/// Ordinary least squares over the rows of `regressors`, solved through the normal equations.
+/// Returns `None` when there are not enough observations or the regressors are collinear.
+pub fn least_squares(regressors: &[Vec<f64>], y: &[f64]) -> Option<LeastSquaresFit> {
+    let observations = y.len();
+    let k = regressors.first()?.len();
+    if observations <= k || regressors.len() != observations {
+        return None
+    }
+
+    let mut xtx = vec![vec![0.0f64; k]; k];
+    let mut xty = vec![0.0f64; k];
+    for (row, &y_value) in regressors.iter().zip(y) {
+        for i in 0..k {
+            xty[i] += row[i] * y_value;
+            for j in 0..k {
+                xtx[i][j] += row[i] * row[j];
+            }
+        }
+    }
+
+    let xtx_inverse = invert(xtx)?;
+    let coefficients: Vec<f64> = (0..k)
+        .map(|i| (0..k).map(|j| xtx_inverse[i][j] * xty[j]).sum())
+        .collect();
+
+    let residuals: Vec<f64> = regressors.iter().zip(y)
+        .map(|(row, &y_value)| y_value - row.iter().zip(&coefficients).map(|(x, b)| x * b).sum::<f64>())
+        .collect();
+    let residual_variance = residuals.iter().map(|e| e * e).sum::<f64>() / (observations - k) as f64;
+    let standard_errors = (0..k)
+        .map(|i| (residual_variance * xtx_inverse[i][i]).sqrt())
+        .collect();
+
+    Some(LeastSquaresFit {
+        coefficients,
+        standard_errors,
+        residuals
+    })
+}
+
+// Gauss-Jordan elimination with partial pivoting.
+fn invert(mut matrix: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for column in 0..n {
+        let pivot_row = (column..n)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot_row][column].abs() < 1e-12 {
+            return None
+        }
+        matrix.swap(column, pivot_row);
+        inverse.swap(column, pivot_row);
+
+        let pivot = matrix[column][column];
+        for j in 0..n {
+            matrix[column][j] /= pivot;
+            inverse[column][j] /= pivot;
+        }
+        for row in 0..n {
+            if row != column {
+                let factor = matrix[row][column];
+                for j in 0..n {
+                    matrix[row][j] -= factor * matrix[column][j];
+                    inverse[row][j] -= factor * inverse[column][j];
+                }
+            }
+        }
+    }
+    Some(inverse)
+}
@@ -0,0 +1,5 @@
+pub mod pair_data;
+pub mod least_squares;
+pub mod cointegration;
+pub mod rolling_ols;
+pub mod pairs_trading_simulator;
\ No newline at end of file
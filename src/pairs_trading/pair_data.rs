@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use chrono::NaiveDate;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+/// Two tickers' bars joined on the dates both of them traded.
+pub struct PairData {
+    pub first_ticker: String,
+    pub second_ticker: String,
+    pub bars: Vec<(StockPriceInfo, StockPriceInfo)>
+}
+
+impl PairData {
+    pub fn new(first_ticker: &str, first: &[StockPriceInfo], second_ticker: &str, second: &[StockPriceInfo]) -> Self {
+        let second_by_date: HashMap<NaiveDate, &StockPriceInfo> = second.iter()
+            .map(|bar| (bar.date, bar))
+            .collect();
+
+        let bars = first.iter()
+            .filter_map(|first_bar| second_by_date.get(&first_bar.date)
+                .map(|&second_bar| (first_bar.clone(), second_bar.clone())))
+            .collect();
+
+        Self {
+            first_ticker: first_ticker.to_string(),
+            second_ticker: second_ticker.to_string(),
+            bars
+        }
+    }
+
+    /// The same pair restricted to the bars before `date`, e.g. the formation window of a backtest.
+    pub fn before(&self, date: NaiveDate) -> Self {
+        Self {
+            first_ticker: self.first_ticker.clone(),
+            second_ticker: self.second_ticker.clone(),
+            bars: self.bars.iter().filter(|(first, _)| first.date < date).cloned().collect()
+        }
+    }
+
+    pub fn closes(&self) -> (Vec<f64>, Vec<f64>) {
+        self.bars.iter()
+            .map(|(first, second)| (first.close as f64, second.close as f64))
+            .unzip()
+    }
+}
@@ -0,0 +1,195 @@
+use chrono::NaiveDate;
+use crate::broker_fee::BrokerFee;
+use crate::pairs_trading::pair_data::PairData;
+use crate::pairs_trading::rolling_ols::RollingOls;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::utils::rolling_window::RollingWindow;
+
+pub struct PairsTradingConfig {
+    pub hedge_ratio_window: usize,
+    pub z_score_window: usize,
+    pub entry_z_score: f64,
+    pub exit_z_score: f64
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpreadPosition {
+    Flat,
+    /// Long the first leg, short `hedge_ratio` of the second.
+    LongSpread,
+    /// Short the first leg, long `hedge_ratio` of the second.
+    ShortSpread
+}
+
+#[derive(Clone, Debug)]
+pub struct LegTrade {
+    pub date: NaiveDate,
+    pub ticker: String,
+    /// Positive for buys (including short covers), negative for sells and short sales.
+    pub shares: i64,
+    pub price: f32,
+    pub fee: f32
+}
+
+pub struct PairsTradingStep {
+    pub date: NaiveDate,
+    pub hedge_ratio: Option<f64>,
+    pub z_score: Option<f64>,
+    pub position: SpreadPosition,
+    pub equity: f32,
+    pub leg_trades: Vec<LegTrade>
+}
+
+pub struct PairsTradingResult {
+    pub leg_trades: Vec<LegTrade>,
+    pub equity_curve: Vec<(NaiveDate, f32)>
+}
+
+pub struct PairsTradingSimulator {
+    config: PairsTradingConfig,
+    broker_fee: Box<dyn BrokerFee>,
+    start_date: NaiveDate,
+    cash: f32,
+    hedge_ratio_ols: RollingOls,
+    spreads: RollingWindow<f64>,
+    position: SpreadPosition,
+    first_leg_shares: i64,
+    second_leg_shares: i64
+}
+
+impl PairsTradingSimulator {
+    pub fn new(invested_cash: f32,
+               start_date: NaiveDate,
+               config: PairsTradingConfig,
+               broker_fee: Box<dyn BrokerFee>) -> Self {
+        Self {
+            hedge_ratio_ols: RollingOls::new(config.hedge_ratio_window),
+            spreads: RollingWindow::new(config.z_score_window),
+            config,
+            broker_fee,
+            start_date,
+            cash: invested_cash,
+            position: SpreadPosition::Flat,
+            first_leg_shares: 0,
+            second_leg_shares: 0
+        }
+    }
+
+    pub fn run(&mut self, pair: &PairData) -> PairsTradingResult {
+        let mut leg_trades = vec![];
+        let mut equity_curve = vec![];
+        for (first, second) in pair.bars.iter() {
+            let step = self.next(first, second);
+            equity_curve.push((step.date, step.equity));
+            leg_trades.extend(step.leg_trades);
+        }
+        PairsTradingResult {
+            leg_trades,
+            equity_curve
+        }
+    }
+
+    pub fn next(&mut self, first: &StockPriceInfo, second: &StockPriceInfo) -> PairsTradingStep {
+        let ols = self.hedge_ratio_ols.next(second.close as f64, first.close as f64);
+        let z_score = ols.and_then(|ols| {
+            let spread = first.close as f64 - ols.slope * second.close as f64 - ols.intercept;
+            self.spreads.add(spread);
+            self.spread_z_score(spread)
+        });
+
+        let mut leg_trades = vec![];
+        if let (Some(ols), Some(z_score)) = (ols, z_score) {
+            if first.date >= self.start_date {
+                let target_position = self.target_position(z_score);
+                if target_position != self.position {
+                    leg_trades = self.rebalance(first, second, ols.slope, target_position);
+                }
+            }
+        }
+
+        PairsTradingStep {
+            date: first.date,
+            hedge_ratio: ols.map(|ols| ols.slope),
+            z_score,
+            position: self.position,
+            equity: self.equity(first.close, second.close),
+            leg_trades
+        }
+    }
+
+    pub fn equity(&self, first_close: f32, second_close: f32) -> f32 {
+        self.cash + self.first_leg_shares as f32 * first_close + self.second_leg_shares as f32 * second_close
+    }
+
+    fn spread_z_score(&self, spread: f64) -> Option<f64> {
+        if !self.spreads.is_full() {
+            return None
+        }
+        let n = self.config.z_score_window as f64;
+        let mean = self.spreads.iter().sum::<f64>() / n;
+        let std_dev = (self.spreads.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+        if std_dev > 0.0 {
+            Some((spread - mean) / std_dev)
+        } else {
+            None
+        }
+    }
+
+    fn target_position(&self, z_score: f64) -> SpreadPosition {
+        match self.position {
+            SpreadPosition::LongSpread if z_score < -self.config.exit_z_score => SpreadPosition::LongSpread,
+            SpreadPosition::ShortSpread if z_score > self.config.exit_z_score => SpreadPosition::ShortSpread,
+            _ if z_score > self.config.entry_z_score => SpreadPosition::ShortSpread,
+            _ if z_score < -self.config.entry_z_score => SpreadPosition::LongSpread,
+            _ => SpreadPosition::Flat
+        }
+    }
+
+    fn rebalance(&mut self,
+                 first: &StockPriceInfo,
+                 second: &StockPriceInfo,
+                 hedge_ratio: f64,
+                 target_position: SpreadPosition) -> Vec<LegTrade> {
+        let mut leg_trades = vec![];
+        leg_trades.extend(self.trade(first, -self.first_leg_shares));
+        leg_trades.extend(self.trade(second, -self.second_leg_shares));
+        self.first_leg_shares = 0;
+        self.second_leg_shares = 0;
+
+        let direction = match target_position {
+            SpreadPosition::LongSpread => 1,
+            SpreadPosition::ShortSpread => -1,
+            SpreadPosition::Flat => 0
+        };
+        if direction != 0 {
+            let first_leg_shares = ((self.cash / 2.0) / first.close) as i64 * direction;
+            let second_leg_shares = -(first_leg_shares as f64 * hedge_ratio).round() as i64;
+            leg_trades.extend(self.trade(first, first_leg_shares));
+            leg_trades.extend(self.trade(second, second_leg_shares));
+            self.first_leg_shares = first_leg_shares;
+            self.second_leg_shares = second_leg_shares;
+        }
+        self.position = target_position;
+        leg_trades
+    }
+
+    fn trade(&mut self, bar: &StockPriceInfo, shares: i64) -> Option<LegTrade> {
+        if shares == 0 {
+            return None
+        }
+        let volume = shares.unsigned_abs() as usize;
+        let fee = if shares > 0 {
+            self.broker_fee.buy_fee(volume, bar.close)
+        } else {
+            self.broker_fee.sell_fee(volume, bar.close)
+        };
+        self.cash = self.cash - shares as f32 * bar.close - fee;
+        Some(LegTrade {
+            date: bar.date,
+            ticker: bar.ticker.clone(),
+            shares,
+            price: bar.close,
+            fee
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug)]
+pub struct OlsResult {
+    pub intercept: f64,
+    pub slope: f64
+}
+
+/// Regression of `y` on `x` over the last `window` observations, updated with running sums.
+pub struct RollingOls {
+    window: usize,
+    observations: VecDeque<(f64, f64)>,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xx: f64,
+    sum_xy: f64
+}
+
+impl RollingOls {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            observations: VecDeque::with_capacity(window),
+            sum_x: 0.0,
+            sum_y: 0.0,
+            sum_xx: 0.0,
+            sum_xy: 0.0
+        }
+    }
+
+    pub fn next(&mut self, x: f64, y: f64) -> Option<OlsResult> {
+        if self.observations.len() == self.window {
+            if let Some((old_x, old_y)) = self.observations.pop_front() {
+                self.sum_x -= old_x;
+                self.sum_y -= old_y;
+                self.sum_xx -= old_x * old_x;
+                self.sum_xy -= old_x * old_y;
+            }
+        }
+        self.observations.push_back((x, y));
+        self.sum_x += x;
+        self.sum_y += y;
+        self.sum_xx += x * x;
+        self.sum_xy += x * y;
+        self.current()
+    }
+
+    pub fn current(&self) -> Option<OlsResult> {
+        if self.observations.len() < self.window {
+            return None
+        }
+        let n = self.observations.len() as f64;
+        let x_variance = n * self.sum_xx - self.sum_x * self.sum_x;
+        if x_variance.abs() < f64::EPSILON {
+            return None
+        }
+        let slope = (n * self.sum_xy - self.sum_x * self.sum_y) / x_variance;
+        Some(OlsResult {
+            intercept: (self.sum_y - slope * self.sum_x) / n,
+            slope
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod momentum_rotation;
+pub mod turtle_trading;
\ No newline at end of file
@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use chrono::{Datelike, Months, NaiveDate};
+use crate::broker_fee::BrokerFee;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+pub struct MomentumRotationConfig {
+    pub lookback_months: u32,
+    /// Most recent months left out of the momentum window, usually 1 to avoid short term reversal.
+    pub skip_months: u32,
+    pub top_k: usize,
+    pub rebalance_every_months: u32
+}
+
+pub struct Rebalance {
+    pub date: NaiveDate,
+    pub holdings: Vec<String>,
+    /// One-way turnover: traded value divided by twice the portfolio equity.
+    pub turnover: f32,
+    pub fees: f32
+}
+
+pub struct MomentumRotationResult {
+    pub equity_curve: Vec<(NaiveDate, f32)>,
+    pub rebalances: Vec<Rebalance>
+}
+
+impl MomentumRotationResult {
+    pub fn average_turnover(&self) -> f32 {
+        if self.rebalances.is_empty() {
+            return 0.0
+        }
+        self.rebalances.iter().map(|rebalance| rebalance.turnover).sum::<f32>() / self.rebalances.len() as f32
+    }
+
+    pub fn total_fees(&self) -> f32 {
+        self.rebalances.iter().map(|rebalance| rebalance.fees).sum()
+    }
+}
+
+type PriceHistory<'a> = BTreeMap<NaiveDate, &'a StockPriceInfo>;
+
+/// Cross-sectional momentum: at the start of every rebalance period ranks the universe by the
+/// total return over the lookback window (known at the previous close) and holds the top K
+/// equally weighted, trading at the open.
+pub struct MomentumRotation {
+    config: MomentumRotationConfig,
+    broker_fee: Box<dyn BrokerFee>,
+    invested_cash: f32,
+    start_date: NaiveDate
+}
+
+impl MomentumRotation {
+    pub fn new(invested_cash: f32,
+               start_date: NaiveDate,
+               config: MomentumRotationConfig,
+               broker_fee: Box<dyn BrokerFee>) -> Self {
+        Self {
+            config,
+            broker_fee,
+            invested_cash,
+            start_date
+        }
+    }
+
+    pub fn run(&self, stock_data: &HashMap<String, Vec<StockPriceInfo>>) -> MomentumRotationResult {
+        let price_histories: HashMap<&String, PriceHistory> = stock_data.iter()
+            .map(|(ticker, bars)| (ticker, bars.iter().map(|bar| (bar.date, bar)).collect()))
+            .collect();
+        let calendar: BTreeSet<NaiveDate> = stock_data.values()
+            .flat_map(|bars| bars.iter().map(|bar| bar.date))
+            .collect();
+
+        let mut cash = self.invested_cash;
+        let mut holdings: HashMap<String, usize> = HashMap::new();
+        let mut months_until_rebalance = 0;
+        let mut previous_date: Option<NaiveDate> = None;
+        let mut equity_curve = vec![];
+        let mut rebalances = vec![];
+
+        for &date in calendar.iter() {
+            if let Some(signal_date) = previous_date.filter(|previous| is_new_month(*previous, date)) {
+                if date >= self.start_date {
+                    if months_until_rebalance == 0 {
+                        let ranking = self.rank(&price_histories, signal_date, date);
+                        rebalances.push(self.rebalance(&price_histories, date, ranking, &mut cash, &mut holdings));
+                        months_until_rebalance = self.config.rebalance_every_months.max(1);
+                    }
+                    months_until_rebalance -= 1;
+                }
+            }
+            if date >= self.start_date {
+                let holdings_value: f32 = holdings.iter()
+                    .map(|(ticker, &shares)| shares as f32 * close_on_or_before(&price_histories[ticker], date).unwrap_or(0.0))
+                    .sum();
+                equity_curve.push((date, cash + holdings_value));
+            }
+            previous_date = Some(date);
+        }
+
+        MomentumRotationResult {
+            equity_curve,
+            rebalances
+        }
+    }
+
+    // Tickers tradable on `execution_date`, best momentum first.
+    fn rank(&self, price_histories: &HashMap<&String, PriceHistory>, signal_date: NaiveDate, execution_date: NaiveDate) -> Vec<String> {
+        let window_start = signal_date - Months::new(self.config.lookback_months);
+        let window_end = signal_date - Months::new(self.config.skip_months);
+
+        let mut momentum: Vec<(String, f32)> = price_histories.iter()
+            .filter(|(_, history)| history.contains_key(&execution_date))
+            .filter(|(_, history)| history.keys().next().is_some_and(|&first_date| first_date <= window_start))
+            .filter_map(|(&ticker, history)| {
+                let start_close = close_on_or_before(history, window_start)?;
+                let end_close = close_on_or_before(history, window_end)?;
+                Some((ticker.clone(), end_close / start_close - 1.0))
+            })
+            .filter(|(_, total_return)| total_return.is_finite())
+            .collect();
+
+        momentum.sort_by(|a, b| b.1.total_cmp(&a.1));
+        momentum.into_iter()
+            .take(self.config.top_k)
+            .map(|(ticker, _)| ticker)
+            .collect()
+    }
+
+    fn rebalance(&self,
+                 price_histories: &HashMap<&String, PriceHistory>,
+                 date: NaiveDate,
+                 target_holdings: Vec<String>,
+                 cash: &mut f32,
+                 holdings: &mut HashMap<String, usize>) -> Rebalance {
+        let execution_price = |ticker: &String| price_histories[ticker].get(&date)
+            .map(|bar| bar.open)
+            .or_else(|| close_on_or_before(&price_histories[ticker], date))
+            .unwrap_or(0.0);
+
+        let equity = *cash + holdings.iter()
+            .map(|(ticker, &shares)| shares as f32 * execution_price(ticker))
+            .sum::<f32>();
+        let target_value = if target_holdings.is_empty() { 0.0 } else { equity / target_holdings.len() as f32 };
+        let target_shares = |ticker: &String| -> usize {
+            if target_holdings.contains(ticker) { (target_value / execution_price(ticker)) as usize } else { 0 }
+        };
+
+        let mut traded_value = 0.0f32;
+        let mut fees = 0.0f32;
+        for (ticker, shares) in holdings.iter_mut() {
+            let target = target_shares(ticker);
+            if target < *shares {
+                let price = execution_price(ticker);
+                let fee = self.broker_fee.sell_fee(*shares - target, price);
+                *cash += (*shares - target) as f32 * price - fee;
+                traded_value += (*shares - target) as f32 * price;
+                fees += fee;
+                *shares = target;
+            }
+        }
+        holdings.retain(|_, shares| *shares > 0);
+
+        for ticker in target_holdings.iter() {
+            let price = execution_price(ticker);
+            let current = holdings.get(ticker).cloned().unwrap_or(0);
+            let mut volume = target_shares(ticker).saturating_sub(current);
+            while volume > 0 && volume as f32 * price + self.broker_fee.buy_fee(volume, price) > *cash {
+                volume -= 1;
+            }
+            if volume > 0 {
+                let fee = self.broker_fee.buy_fee(volume, price);
+                *cash -= volume as f32 * price + fee;
+                traded_value += volume as f32 * price;
+                fees += fee;
+                *holdings.entry(ticker.clone()).or_insert(0) += volume;
+            }
+        }
+
+        Rebalance {
+            date,
+            holdings: target_holdings,
+            turnover: if equity > 0.0 { traded_value / (2.0 * equity) } else { 0.0 },
+            fees
+        }
+    }
+}
+
+fn is_new_month(previous: NaiveDate, today: NaiveDate) -> bool {
+    previous.month() != today.month() || previous.year() != today.year()
+}
+
+fn close_on_or_before(history: &PriceHistory, date: NaiveDate) -> Option<f32> {
+    history.range(..=date).next_back().map(|(_, bar)| bar.close)
+}
@@ -0,0 +1,169 @@
+use chrono::NaiveDate;
+use crate::baselines::buy_and_hold::affordable_volume;
+use crate::broker_fee::BrokerFee;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::strategies::turtle_strategy::{TurtleStrategy, TurtleSystem};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+
+pub struct TurtleSizing {
+    /// Share of the equity one N move of a unit may cost, 0.01 in the original rules.
+    pub risk_per_unit: f32,
+    pub max_units: usize,
+    /// Price move, in N, above the last entry that adds the next unit.
+    pub pyramid_step: f32,
+    /// Distance, in N, of the stop below the last entry.
+    pub stop_multiple: f32
+}
+
+impl Default for TurtleSizing {
+    fn default() -> Self {
+        Self {
+            risk_per_unit: 0.01,
+            max_units: 4,
+            pyramid_step: 0.5,
+            stop_multiple: 2.0
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TurtleTradeKind {
+    Entry,
+    Pyramid,
+    Stop,
+    Exit
+}
+
+pub struct TurtleTrade {
+    pub date: NaiveDate,
+    pub kind: TurtleTradeKind,
+    pub shares: usize,
+    pub price: f32,
+    pub fee: f32
+}
+
+pub struct TurtleTradingResult {
+    pub trades: Vec<TurtleTrade>,
+    pub equity_curve: Vec<(NaiveDate, f32)>
+}
+
+struct OpenPosition {
+    shares: usize,
+    units: usize,
+    n: f32,
+    last_entry_price: f32,
+    stop_price: f32
+}
+
+/// Turtle system with its original money management: positions are bought in units sized so a
+/// move of N costs `risk_per_unit` of the equity, a unit is added every `pyramid_step` N up to
+/// `max_units`, and all units share a stop `stop_multiple` N below the latest entry.
+pub struct TurtleTrading {
+    system: TurtleSystem,
+    sizing: TurtleSizing,
+    broker_fee: Box<dyn BrokerFee>,
+    invested_cash: f32,
+    start_date: NaiveDate
+}
+
+impl TurtleTrading {
+    pub fn new(invested_cash: f32,
+               start_date: NaiveDate,
+               system: TurtleSystem,
+               sizing: TurtleSizing,
+               broker_fee: Box<dyn BrokerFee>) -> Self {
+        Self {
+            system,
+            sizing,
+            broker_fee,
+            invested_cash,
+            start_date
+        }
+    }
+
+    pub fn run(&self, stock_data: &[StockPriceInfo]) -> TurtleTradingResult {
+        let mut strategy = TurtleStrategy::new(self.system);
+        let context = StrategyContext::default();
+        let mut cash = self.invested_cash;
+        let mut position: Option<OpenPosition> = None;
+        let mut yesterday: Option<StockPriceInfo> = None;
+        let mut trades = vec![];
+        let mut equity_curve = vec![];
+
+        for today in stock_data.iter() {
+            let signals = strategy.calculation(today, &yesterday, &context);
+            yesterday = Some(today.clone());
+            if today.date < self.start_date {
+                continue
+            }
+
+            if let Some(open_position) = position.as_ref() {
+                let exit = if today.low <= open_position.stop_price {
+                    Some((TurtleTradeKind::Stop, f32::min(today.open, open_position.stop_price)))
+                } else {
+                    signals.channel_exit_price.map(|price| (TurtleTradeKind::Exit, price))
+                };
+                if let Some((kind, price)) = exit {
+                    let fee = self.broker_fee.sell_fee(open_position.shares, price);
+                    cash += open_position.shares as f32 * price - fee;
+                    trades.push(TurtleTrade { date: today.date, kind, shares: open_position.shares, price, fee });
+                    position = None;
+                }
+            }
+
+            let equity = cash + position.as_ref().map(|open_position| open_position.shares as f32 * today.open).unwrap_or(0.0);
+            match position.as_mut() {
+                None => {
+                    if let Some(price) = signals.entry_price.filter(|_| signals.n > 0.0) {
+                        let shares = self.unit_shares(equity, cash, signals.n, price);
+                        if shares > 0 {
+                            let fee = self.broker_fee.buy_fee(shares, price);
+                            cash -= shares as f32 * price + fee;
+                            trades.push(TurtleTrade { date: today.date, kind: TurtleTradeKind::Entry, shares, price, fee });
+                            position = Some(OpenPosition {
+                                shares,
+                                units: 1,
+                                n: signals.n,
+                                last_entry_price: price,
+                                stop_price: price - self.sizing.stop_multiple * signals.n
+                            });
+                        }
+                    }
+                }
+                Some(open_position) => {
+                    while open_position.units < self.sizing.max_units {
+                        let pyramid_level = open_position.last_entry_price + self.sizing.pyramid_step * open_position.n;
+                        if today.high < pyramid_level {
+                            break
+                        }
+                        let price = f32::max(today.open, pyramid_level);
+                        let shares = self.unit_shares(equity, cash, open_position.n, price);
+                        if shares == 0 {
+                            break
+                        }
+                        let fee = self.broker_fee.buy_fee(shares, price);
+                        cash -= shares as f32 * price + fee;
+                        trades.push(TurtleTrade { date: today.date, kind: TurtleTradeKind::Pyramid, shares, price, fee });
+                        open_position.shares += shares;
+                        open_position.units += 1;
+                        open_position.last_entry_price = price;
+                        open_position.stop_price = price - self.sizing.stop_multiple * open_position.n;
+                    }
+                }
+            }
+
+            let shares_held = position.as_ref().map(|open_position| open_position.shares).unwrap_or(0);
+            equity_curve.push((today.date, cash + shares_held as f32 * today.close));
+        }
+
+        TurtleTradingResult {
+            trades,
+            equity_curve
+        }
+    }
+
+    fn unit_shares(&self, equity: f32, cash: f32, n: f32, price: f32) -> usize {
+        let unit = (self.sizing.risk_per_unit * equity / n) as usize;
+        usize::min(unit, affordable_volume(cash, price, self.broker_fee.as_ref()))
+    }
+}
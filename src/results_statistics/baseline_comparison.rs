@@ -0,0 +1,24 @@
+/// Final values of a strategy and of the passive baselines run over the same window.
+#[derive(Clone, Copy, Debug)]
+pub struct BaselineComparison {
+    pub strategy: f32,
+    pub buy_and_hold: f32,
+    pub dollar_cost_averaging: f32
+}
+
+impl BaselineComparison {
+    /// Return of the strategy relative to buy-and-hold's final value, in percent: positive when the
+    /// strategy beat it, e.g. 10.0 for 11000 against 10000.
+    pub fn excess_over_buy_and_hold(&self) -> f32 {
+        excess_return(self.strategy, self.buy_and_hold)
+    }
+
+    /// Return of the strategy relative to DCA's final value, in percent.
+    pub fn excess_over_dollar_cost_averaging(&self) -> f32 {
+        excess_return(self.strategy, self.dollar_cost_averaging)
+    }
+}
+
+fn excess_return(strategy: f32, baseline: f32) -> f32 {
+    (strategy / baseline - 1.0) * 100.0
+}
@@ -1,3 +1,6 @@
 pub mod monte_carlo;
 pub mod average_roi;
-pub mod profitable_investment;
\ No newline at end of file
+pub mod profitable_investment;
+pub mod baseline_comparison;
+pub mod t_test;
+pub mod seasonality;
\ No newline at end of file
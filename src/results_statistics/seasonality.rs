@@ -0,0 +1,78 @@
+use chrono::{Datelike, Month, Weekday};
+use crate::results_statistics::t_test::welch_t_test;
+use crate::stock_data_reader::resampler::{resample, Timeframe};
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+/// Returns of one weekday or month, in percent, tested against the returns of all the others.
+#[derive(Debug, serde::Serialize)]
+pub struct SeasonalityBucket {
+    pub period: String,
+    pub observations: usize,
+    pub mean_return: f32,
+    pub median_return: f32,
+    pub t_statistic: f32,
+    pub p_value: f32
+}
+
+pub struct SeasonalityReport {
+    /// Close-to-close daily returns grouped by the weekday they ended on.
+    pub by_weekday: Vec<SeasonalityBucket>,
+    /// Returns of completed calendar months grouped by month of year.
+    pub by_month: Vec<SeasonalityBucket>
+}
+
+pub fn seasonality_report(stock_data: &[StockPriceInfo]) -> SeasonalityReport {
+    let daily_returns = returns(stock_data);
+    let monthly_bars = resample(stock_data, Timeframe::Monthly);
+    let monthly_returns = returns(&monthly_bars);
+
+    let weekdays = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+    let by_weekday = weekdays.iter()
+        .map(|weekday| bucket(weekday.to_string(), &daily_returns, |bar| bar.date.weekday() == *weekday))
+        .collect();
+    let by_month = (1..=12u8)
+        .map(|month| {
+            let name = Month::try_from(month).map(|month| month.name()[..3].to_string()).unwrap_or_default();
+            bucket(name, &monthly_returns, |bar| bar.date.month() == month as u32)
+        })
+        .collect();
+
+    SeasonalityReport {
+        by_weekday,
+        by_month
+    }
+}
+
+fn returns(stock_data: &[StockPriceInfo]) -> Vec<(&StockPriceInfo, f64)> {
+    stock_data.windows(2)
+        .map(|window| (&window[1], (window[1].close / window[0].close - 1.0) as f64 * 100.0))
+        .filter(|(_, daily_return)| daily_return.is_finite())
+        .collect()
+}
+
+fn bucket<F>(period: String, returns: &[(&StockPriceInfo, f64)], belongs_to_bucket: F) -> SeasonalityBucket
+where
+    F: Fn(&StockPriceInfo) -> bool
+{
+    let (inside, outside): (Vec<_>, Vec<_>) = returns.iter().partition(|(bar, _)| belongs_to_bucket(bar));
+    let mut inside: Vec<f64> = inside.into_iter().map(|(_, value)| value).collect();
+    let outside: Vec<f64> = outside.into_iter().map(|(_, value)| value).collect();
+    inside.sort_by(|a, b| a.total_cmp(b));
+
+    let mean = if inside.is_empty() { 0.0 } else { inside.iter().sum::<f64>() / inside.len() as f64 };
+    let median = match inside.len() {
+        0 => 0.0,
+        n if n % 2 == 0 => (inside[n / 2 - 1] + inside[n / 2]) / 2.0,
+        n => inside[n / 2]
+    };
+    let t_test = welch_t_test(&inside, &outside);
+
+    SeasonalityBucket {
+        period,
+        observations: inside.len(),
+        mean_return: mean as f32,
+        median_return: median as f32,
+        t_statistic: t_test.as_ref().map(|result| result.t_statistic as f32).unwrap_or(0.0),
+        p_value: t_test.as_ref().map(|result| result.p_value as f32).unwrap_or(1.0)
+    }
+}
@@ -0,0 +1,103 @@
+pub struct TTestResult {
+    pub t_statistic: f64,
+    pub p_value: f64
+}
+
+/// Two-sided Welch's t-test of whether `sample` and `other` have different means.
+pub fn welch_t_test(sample: &[f64], other: &[f64]) -> Option<TTestResult> {
+    if sample.len() < 2 || other.len() < 2 {
+        return None
+    }
+    let (sample_mean, sample_variance) = mean_and_variance(sample);
+    let (other_mean, other_variance) = mean_and_variance(other);
+    let sample_error = sample_variance / sample.len() as f64;
+    let other_error = other_variance / other.len() as f64;
+    let standard_error = (sample_error + other_error).sqrt();
+    if standard_error == 0.0 {
+        return None
+    }
+
+    let t_statistic = (sample_mean - other_mean) / standard_error;
+    let degrees_of_freedom = (sample_error + other_error).powi(2) /
+        (sample_error.powi(2) / (sample.len() - 1) as f64 + other_error.powi(2) / (other.len() - 1) as f64);
+    Some(TTestResult {
+        t_statistic,
+        p_value: student_t_two_sided_p_value(t_statistic, degrees_of_freedom)
+    })
+}
+
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    (mean, variance)
+}
+
+fn student_t_two_sided_p_value(t_statistic: f64, degrees_of_freedom: f64) -> f64 {
+    let x = degrees_of_freedom / (degrees_of_freedom + t_statistic * t_statistic);
+    regularized_incomplete_beta(x, degrees_of_freedom / 2.0, 0.5)
+}
+
+// Numerical Recipes' continued fraction evaluation of I_x(a, b).
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0
+    }
+    if x >= 1.0 {
+        return 1.0
+    }
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut result = d;
+    for m in 1..300 {
+        let m = m as f64;
+        let even_step = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 + even_step * d;
+        d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+        c = 1.0 + even_step / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        result *= d * c;
+
+        let odd_step = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 + odd_step * d;
+        d = if d.abs() < TINY { 1.0 / TINY } else { 1.0 / d };
+        c = 1.0 + odd_step / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        let delta = d * c;
+        result *= delta;
+        if (delta - 1.0).abs() < 1e-12 {
+            break
+        }
+    }
+    result
+}
+
+// Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146, -86.50532032941677, 24.01409824083091,
+        -1.231739572450155, 0.1208650973866179e-2, -0.5395239384953e-5
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let series = COEFFICIENTS.iter().enumerate()
+        .fold(1.000000000190015, |sum, (i, coefficient)| sum + coefficient / (x + 1.0 + i as f64));
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
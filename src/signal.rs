@@ -0,0 +1,34 @@
+#[derive(Clone, Debug)]
+pub struct Signal {
+    pub price: f32,
+    pub strength: f32,
+    pub reason: String
+}
+
+impl Signal {
+    pub fn new(price: f32, strength: f32, reason: &str) -> Self {
+        Self {
+            price,
+            strength: normalize_strength(strength),
+            reason: reason.to_string()
+        }
+    }
+
+    pub fn full_strength(price: f32, reason: &str) -> Self {
+        Self::new(price, 1.0, reason)
+    }
+}
+
+/// Maps how far an indicator went past its trigger level onto 0..1, where `full_scale`
+/// is the distance considered a maximum strength signal.
+pub fn scaled_strength(excess: f32, full_scale: f32) -> f32 {
+    normalize_strength(excess / full_scale)
+}
+
+fn normalize_strength(strength: f32) -> f32 {
+    if strength.is_nan() {
+        0.0
+    } else {
+        strength.clamp(0.0, 1.0)
+    }
+}
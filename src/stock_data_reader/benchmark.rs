@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+use chrono::NaiveDate;
+use crate::stock_data_reader::stock_data_reader::{read_from_file, StockPriceInfo};
+
+/// Market series (e.g. `^ndx` or `qqq.us`) looked up by date while simulating other tickers.
+pub struct Benchmark {
+    bars: HashMap<NaiveDate, StockPriceInfo>
+}
+
+impl Benchmark {
+    pub fn new(stock_data: Vec<StockPriceInfo>) -> Self {
+        Self {
+            bars: stock_data.into_iter().map(|bar| (bar.date, bar)).collect()
+        }
+    }
+
+    pub fn from_file(file_path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !file_path.is_file() {
+            return Err(format!("Benchmark file {} not found", file_path.display()).into());
+        }
+        Ok(Self::new(read_from_file(file_path)))
+    }
+
+    pub fn get(&self, date: NaiveDate) -> Option<&StockPriceInfo> {
+        self.bars.get(&date)
+    }
+}
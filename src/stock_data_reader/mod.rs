@@ -1 +1,3 @@
-pub mod stock_data_reader;
\ No newline at end of file
+pub mod stock_data_reader;
+pub mod resampler;
+pub mod benchmark;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use chrono::{Datelike, NaiveDate};
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Timeframe {
+    Weekly,
+    Monthly
+}
+
+impl Timeframe {
+    fn period(&self, date: NaiveDate) -> (i32, u32) {
+        match self {
+            Timeframe::Weekly => (date.iso_week().year(), date.iso_week().week()),
+            Timeframe::Monthly => (date.year(), date.month())
+        }
+    }
+
+    pub fn same_period(&self, first: NaiveDate, second: NaiveDate) -> bool {
+        self.period(first) == self.period(second)
+    }
+
+    fn stooq_period_code(&self) -> &'static str {
+        match self {
+            Timeframe::Weekly => "W",
+            Timeframe::Monthly => "M"
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Resampler {
+    timeframe: Timeframe,
+    forming_bar: Option<StockPriceInfo>
+}
+
+impl Resampler {
+    pub fn new(timeframe: Timeframe) -> Self {
+        Self {
+            timeframe,
+            forming_bar: None
+        }
+    }
+
+    /// Feeds a daily bar and returns the previous period's bar once `day` opens a new period.
+    /// A period is only known to be complete when the next one starts, so the returned bar never
+    /// contains information from after `day`'s open.
+    pub fn next(&mut self, day: &StockPriceInfo) -> Option<StockPriceInfo> {
+        match self.forming_bar.take() {
+            Some(mut bar) if self.timeframe.same_period(bar.date, day.date) => {
+                bar.date = day.date;
+                bar.high = f32::max(bar.high, day.high);
+                bar.low = f32::min(bar.low, day.low);
+                bar.close = day.close;
+                bar.vol += day.vol;
+                bar.openint = day.openint;
+                self.forming_bar = Some(bar);
+                None
+            }
+            completed_bar => {
+                self.forming_bar = Some(StockPriceInfo {
+                    per: self.timeframe.stooq_period_code().to_string(),
+                    ..day.clone()
+                });
+                completed_bar
+            }
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.forming_bar = None;
+    }
+}
+
+pub fn resample(stock_data: &[StockPriceInfo], timeframe: Timeframe) -> Vec<StockPriceInfo> {
+    let mut resampler = Resampler::new(timeframe);
+    stock_data.iter()
+        .filter_map(|day| resampler.next(day))
+        .collect()
+}
@@ -2,8 +2,12 @@ use crate::StockPriceInfo;
 
 pub trait StopLossTrigger {
     fn should_trigger_stop_loss(&self, stock_price_info: &StockPriceInfo, last_buy_price: f32) -> Option<f32>;
+    /// Stateless stops have nothing to reset.
+    fn reset(&mut self) {}
+    fn fork(&self) -> Box<dyn StopLossTrigger>;
 }
 
+#[derive(Clone)]
 pub struct PercentageStopLoss {
     stop_loss_percentage: f32
 }
@@ -16,12 +20,17 @@ impl PercentageStopLoss {
     }
 }
 
+#[derive(Clone)]
 pub struct NoStopLoss;
 
 impl StopLossTrigger for NoStopLoss {
     fn should_trigger_stop_loss(&self, _: &StockPriceInfo, _: f32) -> Option<f32> {
         None
     }
+
+    fn fork(&self) -> Box<dyn StopLossTrigger> {
+        Box::new(self.clone())
+    }
 }
 
 impl StopLossTrigger for PercentageStopLoss {
@@ -32,6 +41,10 @@ impl StopLossTrigger for PercentageStopLoss {
            None
        }
     }
+
+    fn fork(&self) -> Box<dyn StopLossTrigger> {
+        Box::new(self.clone())
+    }
 }
 
 
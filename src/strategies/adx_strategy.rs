@@ -0,0 +1,65 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::adx::{Adx, AdxResult};
+
+/// Buys when +DI crosses above −DI while ADX confirms a trend of at least `trend_threshold`, and
+/// sells as soon as −DI is back above +DI.
+#[derive(Clone)]
+pub struct AdxStrategy {
+    adx: Adx,
+    trend_threshold: f32
+}
+
+#[derive(Clone)]
+pub struct AdxStrategyResult {
+    pub yesterday: AdxResult,
+    pub today: AdxResult,
+    pub is_ready: bool
+}
+
+impl AdxStrategy {
+    pub fn new(length: usize, trend_threshold: f32) -> Self {
+        Self {
+            adx: Adx::new(length),
+            trend_threshold
+        }
+    }
+}
+
+impl InvestingStrategy<AdxStrategyResult> for AdxStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> AdxStrategyResult {
+        let yesterday = self.adx.current();
+        AdxStrategyResult {
+            yesterday,
+            today: self.adx.next(stock_price_info.high, stock_price_info.low, stock_price_info.close),
+            is_ready: self.adx.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AdxStrategyResult) -> Option<Signal> {
+        let crossed_up = indicator.yesterday.plus_di <= indicator.yesterday.minus_di && indicator.today.plus_di > indicator.today.minus_di;
+        if indicator.is_ready && crossed_up && indicator.today.adx >= self.trend_threshold {
+            let strength = scaled_strength(indicator.today.adx - self.trend_threshold, self.trend_threshold);
+            Some(Signal::new(stock_price_info.close, strength, "plus_di_crossed_above_minus_di"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AdxStrategyResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.today.minus_di > indicator.today.plus_di {
+            Some(Signal::full_strength(stock_price_info.close, "minus_di_above_plus_di"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.adx.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
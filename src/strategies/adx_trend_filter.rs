@@ -0,0 +1,78 @@
+use std::marker::PhantomData;
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::adx::{Adx, AdxResult};
+
+/// Wraps any strategy and lets its buy signals through only while ADX is at least
+/// `trend_threshold`, e.g. to keep crossover strategies out of choppy markets. Sell signals are
+/// never filtered.
+pub struct AdxTrendFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    strategy: S,
+    adx: Adx,
+    trend_threshold: f32,
+    _phantom: PhantomData<T>
+}
+
+#[derive(Clone)]
+pub struct AdxTrendFilterResult<T> {
+    pub strategy_result: T,
+    pub adx: AdxResult,
+    pub is_ready: bool
+}
+
+impl<T, S> AdxTrendFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    pub fn new(strategy: S, adx_length: usize, trend_threshold: f32) -> Self {
+        Self {
+            strategy,
+            adx: Adx::new(adx_length),
+            trend_threshold,
+            _phantom: PhantomData
+        }
+    }
+}
+
+impl<T, S> InvestingStrategy<AdxTrendFilterResult<T>> for AdxTrendFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, context: &StrategyContext) -> AdxTrendFilterResult<T> {
+        AdxTrendFilterResult {
+            strategy_result: self.strategy.calculation(stock_price_info, yesterday, context),
+            adx: self.adx.next(stock_price_info.high, stock_price_info.low, stock_price_info.close),
+            is_ready: self.adx.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AdxTrendFilterResult<T>) -> Option<Signal> {
+        if indicator.is_ready && indicator.adx.adx >= self.trend_threshold {
+            self.strategy.buy_signal(stock_price_info, &indicator.strategy_result)
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AdxTrendFilterResult<T>) -> Option<Signal> {
+        self.strategy.sell_signal(stock_price_info, &indicator.strategy_result)
+    }
+
+    fn reset(&mut self) {
+        self.strategy.reset();
+        self.adx.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(Self {
+            strategy: self.strategy.fork()?,
+            adx: self.adx.clone(),
+            trend_threshold: self.trend_threshold,
+            _phantom: PhantomData
+        })
+    }
+}
@@ -1,9 +1,10 @@
 use tokio::runtime::Runtime;
 use tonic::transport::Channel;
+use crate::signal::{scaled_strength, Signal};
 use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
 use crate::strategies::arima::hello_world::arima_service_client::ArimaServiceClient;
 use crate::strategies::arima::hello_world::ForecastRequest;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 
 pub mod hello_world {
     tonic::include_proto!("arima_connector");
@@ -40,7 +41,7 @@ impl ArimaStrategy {
 }
 
 impl InvestingStrategy<ArimaResult> for ArimaStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> ArimaResult {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> ArimaResult {
         let res = self.tokyo_runtime.block_on(
             async {
                 self.client.forecast(ForecastRequest {
@@ -66,15 +67,28 @@ impl InvestingStrategy<ArimaResult> for ArimaStrategy {
         }
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &ArimaResult) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &ArimaResult) -> Option<Signal> {
         if indicator.forecast > indicator.close_price {
-            Some(stock_price_info.open)
+            let expected_gain = (indicator.forecast - indicator.close_price) / indicator.close_price;
+            Some(Signal::new(stock_price_info.open, scaled_strength(expected_gain, 0.05), "arima_forecast_above_close"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, _: &ArimaResult) -> Option<f32> {
-        Some(stock_price_info.close)
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, _: &ArimaResult) -> Option<Signal> {
+        Some(Signal::full_strength(stock_price_info.close, "arima_daily_exit"))
+    }
+
+    fn reset(&mut self) {
+        self.history.clear()
+    }
+
+    /// The forecasting server is stateless, so a fork only needs its own connection and a copy of the history.
+    fn fork(&self) -> Option<Self> {
+        Some(Self {
+            history: self.history.clone(),
+            ..ArimaStrategy::new()
+        })
     }
 }
\ No newline at end of file
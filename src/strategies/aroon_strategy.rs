@@ -0,0 +1,66 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::aroon::{Aroon, AroonResult};
+
+/// Buys when the Aroon oscillator turns positive with Aroon up at least `strong_level`, i.e. a
+/// recent high, and sells when the oscillator turns negative.
+#[derive(Clone)]
+pub struct AroonStrategy {
+    aroon: Aroon,
+    strong_level: f32
+}
+
+#[derive(Clone)]
+pub struct AroonStrategyResult {
+    pub yesterday: AroonResult,
+    pub today: AroonResult,
+    pub is_ready: bool
+}
+
+impl AroonStrategy {
+    pub fn new(length: usize, strong_level: f32) -> Self {
+        Self {
+            aroon: Aroon::new(length),
+            strong_level
+        }
+    }
+}
+
+impl InvestingStrategy<AroonStrategyResult> for AroonStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> AroonStrategyResult {
+        let is_ready = self.aroon.is_ready();
+        AroonStrategyResult {
+            yesterday: self.aroon.current(),
+            today: self.aroon.next(stock_price_info.high, stock_price_info.low),
+            is_ready
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AroonStrategyResult) -> Option<Signal> {
+        let crossed_up = indicator.yesterday.oscillator() <= 0.0 && indicator.today.oscillator() > 0.0;
+        if indicator.is_ready && crossed_up && indicator.today.up >= self.strong_level {
+            let strength = scaled_strength(indicator.today.oscillator(), 100.0);
+            Some(Signal::new(stock_price_info.close, strength, "aroon_up_crossed_above_down"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &AroonStrategyResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.today.oscillator() < 0.0 {
+            let strength = scaled_strength(-indicator.today.oscillator(), 100.0);
+            Some(Signal::new(stock_price_info.close, strength, "aroon_down_above_up"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.aroon.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::bollinger_bands::{BollingerBands, BollingerBandsResult};
+
+/// Buys a close below the lower Bollinger band and sells once %B gets back to `exit_percent_b`,
+/// 0.5 being the middle band.
+#[derive(Clone)]
+pub struct BollingerMeanReversionStrategy {
+    bollinger_bands: BollingerBands,
+    exit_percent_b: f32
+}
+
+#[derive(Clone)]
+pub struct BollingerMeanReversionResult {
+    pub bands: BollingerBandsResult,
+    pub is_ready: bool
+}
+
+impl BollingerMeanReversionStrategy {
+    pub fn new(length: usize, band_width: f32, exit_percent_b: f32) -> Self {
+        Self {
+            bollinger_bands: BollingerBands::new(length, band_width),
+            exit_percent_b
+        }
+    }
+}
+
+impl InvestingStrategy<BollingerMeanReversionResult> for BollingerMeanReversionStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> BollingerMeanReversionResult {
+        BollingerMeanReversionResult {
+            bands: self.bollinger_bands.next(stock_price_info.close),
+            is_ready: self.bollinger_bands.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &BollingerMeanReversionResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.bands.percent_b < 0.0 {
+            let strength = scaled_strength(-indicator.bands.percent_b, 0.5);
+            Some(Signal::new(stock_price_info.close, strength, "close_below_lower_bollinger_band"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &BollingerMeanReversionResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.bands.percent_b >= self.exit_percent_b {
+            Some(Signal::full_strength(stock_price_info.close, "reverted_to_bollinger_mean"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.bollinger_bands.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::bollinger_bands::{BollingerBands, BollingerBandsResult};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Bollinger squeeze breakout: a squeeze is a bandwidth at its lowest of the last
+/// `squeeze_lookback` bars. A close above the upper band within `breakout_window` bars of a
+/// squeeze is bought, and the position is sold on a close below the middle band.
+#[derive(Clone)]
+pub struct BollingerSqueezeStrategy {
+    bollinger_bands: BollingerBands,
+    bandwidths: RollingWindow<f32>,
+    breakout_window: usize,
+    bars_since_squeeze: Option<usize>
+}
+
+#[derive(Clone)]
+pub struct BollingerSqueezeResult {
+    pub bands: BollingerBandsResult,
+    pub in_squeeze: bool,
+    pub bars_since_squeeze: Option<usize>
+}
+
+impl BollingerSqueezeStrategy {
+    pub fn new(length: usize, band_width: f32, squeeze_lookback: usize, breakout_window: usize) -> Self {
+        Self {
+            bollinger_bands: BollingerBands::new(length, band_width),
+            bandwidths: RollingWindow::new(squeeze_lookback),
+            breakout_window,
+            bars_since_squeeze: None
+        }
+    }
+}
+
+impl InvestingStrategy<BollingerSqueezeResult> for BollingerSqueezeStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> BollingerSqueezeResult {
+        let bands = self.bollinger_bands.next(stock_price_info.close);
+        if !self.bollinger_bands.is_ready() {
+            return BollingerSqueezeResult { bands, in_squeeze: false, bars_since_squeeze: None }
+        }
+
+        let lowest_bandwidth = self.bandwidths.iter().cloned().fold(f32::INFINITY, f32::min);
+        let in_squeeze = self.bandwidths.is_full() && bands.bandwidth <= lowest_bandwidth;
+        self.bandwidths.add(bands.bandwidth);
+        self.bars_since_squeeze = if in_squeeze {
+            Some(0)
+        } else {
+            self.bars_since_squeeze.map(|bars| bars + 1).filter(|bars| *bars <= self.breakout_window)
+        };
+
+        BollingerSqueezeResult {
+            bands,
+            in_squeeze,
+            bars_since_squeeze: self.bars_since_squeeze
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &BollingerSqueezeResult) -> Option<Signal> {
+        if indicator.bars_since_squeeze.is_some() && stock_price_info.close > indicator.bands.upper_band {
+            let band_distance = indicator.bands.upper_band - indicator.bands.middle_band;
+            let strength = scaled_strength(stock_price_info.close - indicator.bands.upper_band, band_distance);
+            Some(Signal::new(stock_price_info.close, strength, "bollinger_squeeze_breakout"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &BollingerSqueezeResult) -> Option<Signal> {
+        if self.bollinger_bands.is_ready() && stock_price_info.close < indicator.bands.middle_band {
+            Some(Signal::full_strength(stock_price_info.close, "close_below_bollinger_middle_band"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.bollinger_bands.reset();
+        self.bandwidths.clear();
+        self.bars_since_squeeze = None;
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
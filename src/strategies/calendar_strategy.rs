@@ -0,0 +1,82 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::utils::trading_calendar::TradingCalendar;
+
+/// Trading sessions during which a calendar strategy wants to be invested.
+#[derive(Clone)]
+pub enum CalendarWindow {
+    DaysOfWeek(Vec<Weekday>),
+    /// The last `last_days` trading sessions of a month and the first `first_days` of the next one.
+    TurnOfMonth { last_days: u32, first_days: u32 },
+    /// Months numbered from 1 (January).
+    MonthsOfYear(Vec<u32>),
+    /// The `days` trading sessions right before an exchange holiday.
+    PreHoliday { days: u32 }
+}
+
+impl CalendarWindow {
+    pub fn contains(&self, session: NaiveDate) -> bool {
+        match self {
+            CalendarWindow::DaysOfWeek(weekdays) => weekdays.contains(&session.weekday()),
+            CalendarWindow::TurnOfMonth { last_days, first_days } =>
+                TradingCalendar::trading_days_to_month_end(session) <= *last_days ||
+                    TradingCalendar::trading_day_of_month(session) <= *first_days,
+            CalendarWindow::MonthsOfYear(months) => months.contains(&session.month()),
+            CalendarWindow::PreHoliday { days } => TradingCalendar::trading_days_to_holiday(session, *days)
+                .is_some_and(|sessions_left| sessions_left >= 1 && sessions_left <= *days)
+        }
+    }
+}
+
+/// Holds the stock through the next session whenever that session is inside all of the windows.
+/// Orders are filled at the close, so deciding on the next session is what avoids look-ahead.
+#[derive(Clone)]
+pub struct CalendarStrategy {
+    windows: Vec<CalendarWindow>
+}
+
+#[derive(Clone)]
+pub struct CalendarResult {
+    pub next_session: NaiveDate,
+    pub hold_next_session: bool
+}
+
+impl CalendarStrategy {
+    pub fn new(windows: Vec<CalendarWindow>) -> Self {
+        Self { windows }
+    }
+}
+
+impl InvestingStrategy<CalendarResult> for CalendarStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> CalendarResult {
+        let next_session = TradingCalendar::next_trading_day(stock_price_info.date);
+        CalendarResult {
+            next_session,
+            hold_next_session: self.windows.iter().all(|window| window.contains(next_session))
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &CalendarResult) -> Option<Signal> {
+        if indicator.hold_next_session {
+            Some(Signal::full_strength(stock_price_info.close, "calendar_window_opens"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &CalendarResult) -> Option<Signal> {
+        if !indicator.hold_next_session {
+            Some(Signal::full_strength(stock_price_info.close, "calendar_window_closes"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::candlestick_patterns::{CandlestickPattern, CandlestickPatterns, CandlestickPatternsResult};
+
+/// Buys when any of the entry patterns completes and sells on any of the exit patterns.
+#[derive(Clone)]
+pub struct CandlestickPatternStrategy {
+    candlestick_patterns: CandlestickPatterns,
+    entry_patterns: Vec<CandlestickPattern>,
+    exit_patterns: Vec<CandlestickPattern>,
+    pass_through_exits: bool
+}
+
+impl CandlestickPatternStrategy {
+    pub fn new(entry_patterns: Vec<CandlestickPattern>, exit_patterns: Vec<CandlestickPattern>) -> Self {
+        Self {
+            candlestick_patterns: CandlestickPatterns::new(),
+            entry_patterns,
+            exit_patterns,
+            pass_through_exits: false
+        }
+    }
+
+    /// Entry-only component for `ChainedInvestingStrategy`: it confirms entries of the other
+    /// strategy with a pattern and never blocks its exits.
+    pub fn entry_filter(entry_patterns: Vec<CandlestickPattern>) -> Self {
+        Self {
+            pass_through_exits: true,
+            ..Self::new(entry_patterns, vec![])
+        }
+    }
+}
+
+fn first_matching(patterns: &[CandlestickPattern], indicator: &CandlestickPatternsResult) -> Option<CandlestickPattern> {
+    patterns.iter().cloned().find(|pattern| indicator.contains(*pattern))
+}
+
+impl InvestingStrategy<CandlestickPatternsResult> for CandlestickPatternStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> CandlestickPatternsResult {
+        self.candlestick_patterns.next(stock_price_info)
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &CandlestickPatternsResult) -> Option<Signal> {
+        first_matching(&self.entry_patterns, indicator)
+            .map(|pattern| Signal::full_strength(stock_price_info.close, pattern.name()))
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &CandlestickPatternsResult) -> Option<Signal> {
+        if self.pass_through_exits {
+            return Some(Signal::full_strength(stock_price_info.close, "candlestick_entry_filter"))
+        }
+        first_matching(&self.exit_patterns, indicator)
+            .map(|pattern| Signal::full_strength(stock_price_info.close, pattern.name()))
+    }
+
+    fn reset(&mut self) {
+        self.candlestick_patterns.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
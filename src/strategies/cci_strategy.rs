@@ -0,0 +1,54 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::cci::Cci;
+
+/// CCI is unbounded, so signal strength reaches its maximum `band` points past the band.
+#[derive(Clone)]
+pub struct CciStrategy {
+    cci: Cci,
+    lower_band: f32,
+    higher_band: f32
+}
+
+impl CciStrategy {
+    pub fn new(length: usize, lower_band: f32, higher_band: f32) -> Self {
+        CciStrategy {
+            cci: Cci::new(length),
+            lower_band,
+            higher_band
+        }
+    }
+}
+
+impl InvestingStrategy<f32> for CciStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> f32 {
+        self.cci.next(stock_price_info.high, stock_price_info.low, stock_price_info.close)
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.cci.is_ready() && *indicator < self.lower_band {
+            let strength = scaled_strength(self.lower_band - *indicator, self.lower_band.abs());
+            Some(Signal::new(stock_price_info.close, strength, "cci_oversold"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.cci.is_ready() && *indicator > self.higher_band {
+            let strength = scaled_strength(*indicator - self.higher_band, self.higher_band.abs());
+            Some(Signal::new(stock_price_info.close, strength, "cci_overbought"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cci.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
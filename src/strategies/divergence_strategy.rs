@@ -0,0 +1,91 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::divergence::{Divergence, DivergenceDetector, DivergenceKind, OscillatorSource};
+use crate::technical_indicator::macd::Macd;
+use crate::technical_indicator::obv::Obv;
+use crate::technical_indicator::rsi::Rsi;
+
+/// Buys on bullish and sells on bearish divergences between price pivots and any oscillator.
+/// Hidden divergences (trend continuation) are only traded when enabled.
+#[derive(Clone)]
+pub struct DivergenceStrategy<O: OscillatorSource> {
+    oscillator: O,
+    detector: DivergenceDetector,
+    trade_hidden_divergences: bool
+}
+
+#[derive(Clone)]
+pub struct DivergenceResult {
+    pub oscillator: f32,
+    pub divergence: Option<Divergence>
+}
+
+impl<O: OscillatorSource> DivergenceStrategy<O> {
+    pub fn new(oscillator: O, detector: DivergenceDetector, trade_hidden_divergences: bool) -> Self {
+        Self {
+            oscillator,
+            detector,
+            trade_hidden_divergences
+        }
+    }
+
+    fn divergence_signal(&self, stock_price_info: &StockPriceInfo, indicator: &DivergenceResult, bullish: bool) -> Option<Signal> {
+        let divergence = indicator.divergence.filter(|divergence| divergence.kind.is_bullish() == bullish)?;
+        let reason = match divergence.kind {
+            DivergenceKind::RegularBullish => "regular_bullish_divergence",
+            DivergenceKind::HiddenBullish if self.trade_hidden_divergences => "hidden_bullish_divergence",
+            DivergenceKind::RegularBearish => "regular_bearish_divergence",
+            DivergenceKind::HiddenBearish if self.trade_hidden_divergences => "hidden_bearish_divergence",
+            _ => return None
+        };
+        let strength = scaled_strength(divergence.oscillator_change.abs(), 0.5);
+        Some(Signal::new(stock_price_info.close, strength, reason))
+    }
+}
+
+impl DivergenceStrategy<Macd> {
+    pub fn default() -> Self {
+        Self::new(Macd::default(), DivergenceDetector::new(5, 5, 0.02, 0.1, 60), false)
+    }
+}
+
+impl DivergenceStrategy<Rsi> {
+    pub fn with_rsi(length: usize) -> Self {
+        Self::new(Rsi::new(length), DivergenceDetector::new(5, 5, 0.02, 0.1, 60), false)
+    }
+}
+
+impl DivergenceStrategy<Obv> {
+    /// Price against on-balance volume, i.e. moves not confirmed by volume.
+    pub fn with_obv() -> Self {
+        Self::new(Obv::new(), DivergenceDetector::new(5, 5, 0.02, 0.1, 60), false)
+    }
+}
+
+impl<O: OscillatorSource> InvestingStrategy<DivergenceResult> for DivergenceStrategy<O> {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> DivergenceResult {
+        let oscillator = self.oscillator.oscillator_value(stock_price_info);
+        DivergenceResult {
+            oscillator,
+            divergence: self.detector.next(stock_price_info.low, stock_price_info.high, oscillator)
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &DivergenceResult) -> Option<Signal> {
+        self.divergence_signal(stock_price_info, indicator, true)
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &DivergenceResult) -> Option<Signal> {
+        self.divergence_signal(stock_price_info, indicator, false)
+    }
+
+    fn reset(&mut self) {
+        self.oscillator.reset_oscillator();
+        self.detector.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ema::Ema;
+use crate::technical_indicator::percent_off_ath::{HighReference, PercentOffAth};
+
+/// "Buy the dip": enters once the close is at least `entry_percent_off_high` below the reference
+/// high and exits when it recovers to within `exit_percent_off_high` of it. A fixed profit target
+/// is left to `PercentageTakeProfit`, which knows the actual entry price.
+#[derive(Clone)]
+pub struct DrawdownMeanReversionStrategy {
+    percent_off_high: PercentOffAth,
+    entry_percent_off_high: f32,
+    exit_percent_off_high: f32,
+    trend_ema: Option<Ema>
+}
+
+#[derive(Clone)]
+pub struct DrawdownMeanReversionResult {
+    pub percent_off_high: f32,
+    pub reference_high: f32,
+    /// Whether the trend EMA rose today; always true without a trend filter.
+    pub trend_rising: bool
+}
+
+impl From<DrawdownMeanReversionResult> for Vec<f32> {
+    fn from(result: DrawdownMeanReversionResult) -> Self {
+        vec![result.percent_off_high, result.reference_high]
+    }
+}
+
+impl DrawdownMeanReversionStrategy {
+    pub fn new(high_reference: HighReference, entry_percent_off_high: f32, exit_percent_off_high: f32) -> Self {
+        Self {
+            percent_off_high: PercentOffAth::with_high_reference(high_reference),
+            entry_percent_off_high,
+            exit_percent_off_high,
+            trend_ema: None
+        }
+    }
+
+    /// Same as `new`, but only buys dips while the `trend_ema_length` EMA is rising.
+    pub fn with_trend_filter(high_reference: HighReference,
+                             entry_percent_off_high: f32,
+                             exit_percent_off_high: f32,
+                             trend_ema_length: usize) -> Self {
+        Self {
+            trend_ema: Some(Ema::new(trend_ema_length)),
+            ..Self::new(high_reference, entry_percent_off_high, exit_percent_off_high)
+        }
+    }
+}
+
+impl InvestingStrategy<DrawdownMeanReversionResult> for DrawdownMeanReversionStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> DrawdownMeanReversionResult {
+        let trend_rising = match self.trend_ema.as_mut() {
+            Some(trend_ema) => {
+                let yesterday_ema = trend_ema.current();
+                trend_ema.next(stock_price_info.close) > yesterday_ema
+            }
+            None => true
+        };
+        DrawdownMeanReversionResult {
+            percent_off_high: self.percent_off_high.next_with_high(stock_price_info.high, stock_price_info.close),
+            reference_high: self.percent_off_high.reference_high(),
+            trend_rising
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &DrawdownMeanReversionResult) -> Option<Signal> {
+        if indicator.trend_rising && indicator.percent_off_high >= self.entry_percent_off_high {
+            let strength = scaled_strength(indicator.percent_off_high - self.entry_percent_off_high, self.entry_percent_off_high);
+            Some(Signal::new(stock_price_info.close, strength, "drawdown_from_high"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &DrawdownMeanReversionResult) -> Option<Signal> {
+        if indicator.percent_off_high <= self.exit_percent_off_high {
+            Some(Signal::full_strength(stock_price_info.close, "recovered_toward_high"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.percent_off_high.reset();
+        if let Some(trend_ema) = self.trend_ema.as_mut() {
+            trend_ema.reset()
+        }
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
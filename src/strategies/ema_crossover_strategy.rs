@@ -1,24 +1,42 @@
 use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
-use crate::technical_indicator::ema::Ema;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ema::{Ema, EmaSeed};
 use crate::technical_indicator::keltner_channel::KeltnerChannelResult;
+use crate::technical_indicator::price_source::PriceSource;
 
+#[derive(Clone)]
 pub struct EmaCrossoverStrategy {
     ema_short: Ema,
-    ema_long: Ema
+    ema_long: Ema,
+    source: PriceSource
 }
 
 #[derive(Clone)]
 pub struct EmaCrossoverResult {
     ema_short: f32,
-    ema_long: f32
+    ema_long: f32,
+    is_ready: bool
 }
 
 impl EmaCrossoverStrategy {
     pub fn new(ema_short_length: usize, ema_long_length: usize) -> Self {
+        Self::with_seed(ema_short_length, ema_long_length, EmaSeed::Zero)
+    }
+
+    /// Except with the legacy `EmaSeed::Zero`, no signal is given until both EMAs are seeded.
+    pub fn with_seed(ema_short_length: usize, ema_long_length: usize, seed: EmaSeed) -> Self {
         Self {
-            ema_short: Ema::new(ema_short_length),
-            ema_long: Ema::new(ema_long_length)
+            ema_short: Ema::with_seed(ema_short_length, seed),
+            ema_long: Ema::with_seed(ema_long_length, seed),
+            source: PriceSource::Close
+        }
+    }
+
+    pub fn with_source(ema_short_length: usize, ema_long_length: usize, source: PriceSource) -> Self {
+        Self {
+            source,
+            ..Self::new(ema_short_length, ema_long_length)
         }
     }
 }
@@ -30,29 +48,42 @@ impl Into<Vec<f32>> for EmaCrossoverResult {
 }
 
 impl InvestingStrategy<EmaCrossoverResult> for EmaCrossoverStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> EmaCrossoverResult {
-        let new_ema_short = self.ema_short.next(stock_price_info.close);
-        let new_ema_long = self.ema_long.next(stock_price_info.close);
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> EmaCrossoverResult {
+        let price = self.source.value(stock_price_info);
+        let new_ema_short = self.ema_short.next(price);
+        let new_ema_long = self.ema_long.next(price);
 
         EmaCrossoverResult {
             ema_short: new_ema_short,
-            ema_long: new_ema_long
+            ema_long: new_ema_long,
+            is_ready: self.ema_long.seed() == EmaSeed::Zero || (self.ema_short.is_ready() && self.ema_long.is_ready())
         }
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaCrossoverResult) -> Option<f32> {
-        if indicator.ema_short > indicator.ema_long {
-            Some(stock_price_info.close)
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaCrossoverResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.ema_short > indicator.ema_long {
+            let spread = (indicator.ema_short - indicator.ema_long) / indicator.ema_long;
+            Some(Signal::new(stock_price_info.close, scaled_strength(spread, 0.05), "ema_short_above_long"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaCrossoverResult) -> Option<f32> {
-        if indicator.ema_short < indicator.ema_long {
-            Some(stock_price_info.close)
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaCrossoverResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.ema_short < indicator.ema_long {
+            let spread = (indicator.ema_long - indicator.ema_short) / indicator.ema_long;
+            Some(Signal::new(stock_price_info.close, scaled_strength(spread, 0.05), "ema_short_below_long"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        self.ema_short.reset();
+        self.ema_long.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
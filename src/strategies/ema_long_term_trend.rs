@@ -1,16 +1,28 @@
+use std::sync::Arc;
 use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 use crate::technical_indicator::ema::Ema;
 
+/// Where the EMA comes from: computed bar by bar, or read from a column computed beforehand over
+/// the same bars, e.g. one shared through an `IndicatorCache` by a grid search.
+#[derive(Clone)]
+enum EmaSource {
+    Streaming(Ema),
+    Precomputed { column: Arc<Vec<f32>>, position: usize }
+}
+
+#[derive(Clone)]
 pub struct EmaLongTermTrendStrategy {
-    ema: Ema,
+    ema: EmaSource,
     buy_percentage_diff_from_ema: f32,
     sell_percentage_diff_from_ema: f32
 }
 
 #[derive(Clone)]
 pub struct EmaLongTermTrendResult {
-    ema: f32
+    /// None when fed more bars than the precomputed column holds.
+    ema: Option<f32>
 }
 
 impl EmaLongTermTrendStrategy {
@@ -18,7 +30,19 @@ impl EmaLongTermTrendStrategy {
                buy_percentage_diff_from_ema: f32,
                sell_percentage_diff_from_ema: f32) -> Self {
         Self {
-            ema: Ema::new(ema_length),
+            ema: EmaSource::Streaming(Ema::new(ema_length)),
+            buy_percentage_diff_from_ema,
+            sell_percentage_diff_from_ema
+        }
+    }
+
+    /// Uses an EMA column computed over the bars the strategy will be fed, from the first one.
+    /// Bars past the end of the column give no signal.
+    pub fn with_precomputed_ema(ema_column: Arc<Vec<f32>>,
+                                buy_percentage_diff_from_ema: f32,
+                                sell_percentage_diff_from_ema: f32) -> Self {
+        Self {
+            ema: EmaSource::Precomputed { column: ema_column, position: 0 },
             buy_percentage_diff_from_ema,
             sell_percentage_diff_from_ema
         }
@@ -26,28 +50,50 @@ impl EmaLongTermTrendStrategy {
 }
 
 impl InvestingStrategy<EmaLongTermTrendResult> for EmaLongTermTrendStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>) -> EmaLongTermTrendResult {
-        let ema = self.ema.next(stock_price_info.close);
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> EmaLongTermTrendResult {
+        let ema = match &mut self.ema {
+            EmaSource::Streaming(ema) => Some(ema.next(stock_price_info.close)),
+            EmaSource::Precomputed { column, position } => {
+                let ema = column.get(*position).copied();
+                *position += 1;
+                ema
+            }
+        };
         EmaLongTermTrendResult {
             ema
         }
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaLongTermTrendResult) -> Option<f32> {
-        let percentage_change = (stock_price_info.close - indicator.ema)/indicator.ema;
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaLongTermTrendResult) -> Option<Signal> {
+        let ema = indicator.ema?;
+        let percentage_change = (stock_price_info.close - ema)/ema;
         if percentage_change > self.buy_percentage_diff_from_ema {
-            Some(stock_price_info.close)
+            let strength = scaled_strength(percentage_change - self.buy_percentage_diff_from_ema, 0.1);
+            Some(Signal::new(stock_price_info.close, strength, "close_above_long_term_ema"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaLongTermTrendResult) -> Option<f32> {
-        let percentage_change = (indicator.ema - stock_price_info.close)/stock_price_info.close;
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaLongTermTrendResult) -> Option<Signal> {
+        let ema = indicator.ema?;
+        let percentage_change = (ema - stock_price_info.close)/stock_price_info.close;
         if percentage_change > self.sell_percentage_diff_from_ema {
-            Some(stock_price_info.close)
+            let strength = scaled_strength(percentage_change - self.sell_percentage_diff_from_ema, 0.1);
+            Some(Signal::new(stock_price_info.close, strength, "close_below_long_term_ema"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        match &mut self.ema {
+            EmaSource::Streaming(ema) => ema.reset(),
+            EmaSource::Precomputed { position, .. } => *position = 0
+        }
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use crate::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
-use crate::technical_indicator::ema::Ema;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::price_source::PriceSource;
 
 #[derive(Clone)]
 pub struct EmaStrategyResult {
@@ -8,13 +10,18 @@ pub struct EmaStrategyResult {
     yesterday_sell: f32,
     today_buy: f32,
     today_sell: f32,
+    /// Whether both EMAs were seeded before today, so that the inclinations are meaningful.
+    /// Always true with the legacy `EmaSeed::Zero`.
+    is_ready: bool
 }
 
+#[derive(Clone)]
 pub struct GrowingEmaStrategy {
     buy_ema: Ema,
     sell_ema: Ema,
     buy_inclination: f32,
-    sell_inclination: f32
+    sell_inclination: f32,
+    source: PriceSource
 }
 
 impl GrowingEmaStrategy {
@@ -23,7 +30,26 @@ impl GrowingEmaStrategy {
             buy_ema: Ema::new(ema_length),
             sell_ema: Ema::new(ema_length),
             buy_inclination,
-            sell_inclination
+            sell_inclination,
+            source: PriceSource::Close
+        }
+    }
+
+    pub fn with_source(ema_length: usize, buy_inclination: f32, sell_inclination: f32, source: PriceSource) -> Self {
+        Self {
+            source,
+            ..Self::new(ema_length, buy_inclination, sell_inclination)
+        }
+    }
+
+    /// Except with the legacy `EmaSeed::Zero`, no signal is given until both EMAs are seeded.
+    pub fn with_seed(ema_length: usize, buy_inclination: f32, sell_inclination: f32, seed: EmaSeed) -> Self {
+        Self {
+            buy_ema: Ema::with_seed(ema_length, seed),
+            sell_ema: Ema::with_seed(ema_length, seed),
+            buy_inclination,
+            sell_inclination,
+            source: PriceSource::Close
         }
     }
 
@@ -35,36 +61,53 @@ impl GrowingEmaStrategy {
             buy_ema: Ema::new(buy_ema_length),
             sell_ema: Ema::new(sell_ema_length),
             buy_inclination,
-            sell_inclination
+            sell_inclination,
+            source: PriceSource::Close
         }
     }
 }
 
 impl InvestingStrategy<EmaStrategyResult> for GrowingEmaStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>) -> EmaStrategyResult {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> EmaStrategyResult {
+        let price = self.source.value(stock_price_info);
+        let is_ready = self.buy_ema.seed() == EmaSeed::Zero || (self.buy_ema.is_ready() && self.sell_ema.is_ready());
         EmaStrategyResult {
+            is_ready,
             yesterday_buy: self.buy_ema.current(),
             yesterday_sell: self.sell_ema.current(),
-            today_buy: self.buy_ema.next(stock_price_info.close),
-            today_sell: self.sell_ema.next(stock_price_info.close),
+            today_buy: self.buy_ema.next(price),
+            today_sell: self.sell_ema.next(price),
         }
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaStrategyResult) -> Option<f32> {
-        if calculate_inclination(indicator.yesterday_buy, indicator.today_buy) > self.buy_inclination {
-            Some(stock_price_info.close)
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaStrategyResult) -> Option<Signal> {
+        let inclination = calculate_inclination(indicator.yesterday_buy, indicator.today_buy);
+        if indicator.is_ready && inclination > self.buy_inclination {
+            let strength = scaled_strength(inclination - self.buy_inclination, 90.0 - self.buy_inclination);
+            Some(Signal::new(stock_price_info.close, strength, "ema_rising"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaStrategyResult) -> Option<f32> {
-        if calculate_inclination(indicator.yesterday_sell, indicator.today_sell) < self.sell_inclination {
-            Some(stock_price_info.close)
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &EmaStrategyResult) -> Option<Signal> {
+        let inclination = calculate_inclination(indicator.yesterday_sell, indicator.today_sell);
+        if indicator.is_ready && inclination < self.sell_inclination {
+            let strength = scaled_strength(self.sell_inclination - inclination, self.sell_inclination + 90.0);
+            Some(Signal::new(stock_price_info.close, strength, "ema_falling"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        self.buy_ema.reset();
+        self.sell_ema.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 fn calculate_inclination(yesterday_ema: f32, today_ema: f32) -> f32 {
@@ -0,0 +1,67 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::stock_data_reader::resampler::Timeframe;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ema::Ema;
+
+/// Trend filter calculated on completed weekly or monthly bars, meant to be chained with a
+/// daily entry strategy, e.g. a weekly EMA filter in front of `RsiStrategy`.
+#[derive(Clone)]
+pub struct HigherTimeframeTrendStrategy {
+    timeframe: Timeframe,
+    ema: Ema,
+    last_completed_close: Option<f32>
+}
+
+#[derive(Clone)]
+pub struct HigherTimeframeTrendResult {
+    pub last_completed_close: Option<f32>,
+    pub ema: f32
+}
+
+impl HigherTimeframeTrendStrategy {
+    pub fn new(timeframe: Timeframe, ema_length: usize) -> Self {
+        Self {
+            timeframe,
+            ema: Ema::new(ema_length),
+            last_completed_close: None
+        }
+    }
+}
+
+impl InvestingStrategy<HigherTimeframeTrendResult> for HigherTimeframeTrendStrategy {
+    fn calculation(&mut self, _: &StockPriceInfo, _: &Option<StockPriceInfo>, context: &StrategyContext) -> HigherTimeframeTrendResult {
+        if let Some(completed_bar) = context.completed_bar(self.timeframe) {
+            self.ema.next(completed_bar.close);
+            self.last_completed_close = Some(completed_bar.close);
+        }
+        HigherTimeframeTrendResult {
+            last_completed_close: self.last_completed_close,
+            ema: self.ema.current()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &HigherTimeframeTrendResult) -> Option<Signal> {
+        match indicator.last_completed_close {
+            Some(close) if close > indicator.ema => {
+                let strength = scaled_strength((close - indicator.ema) / indicator.ema, 0.1);
+                Some(Signal::new(stock_price_info.close, strength, "higher_timeframe_uptrend"))
+            }
+            _ => None
+        }
+    }
+
+    // The filter only gates entries, exits are left to the strategy it is chained with.
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, _: &HigherTimeframeTrendResult) -> Option<Signal> {
+        Some(Signal::full_strength(stock_price_info.close, "higher_timeframe_filter"))
+    }
+
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.last_completed_close = None;
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
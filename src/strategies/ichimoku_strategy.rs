@@ -0,0 +1,105 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ichimoku::{Ichimoku, IchimokuResult};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IchimokuRule {
+    /// Buys a close breaking above the cloud with the Chikou span above the price it is plotted
+    /// against and a green cloud ahead, sells a close below the cloud.
+    CloudBreakout,
+    /// Buys Tenkan crossing above Kijun while the close is above the cloud, sells Tenkan
+    /// crossing below Kijun.
+    TkCross
+}
+
+#[derive(Clone)]
+pub struct IchimokuStrategy {
+    ichimoku: Ichimoku,
+    rule: IchimokuRule
+}
+
+#[derive(Clone)]
+pub struct IchimokuStrategyResult {
+    pub yesterday: IchimokuResult,
+    pub today: IchimokuResult,
+    pub yesterday_close: Option<f32>,
+    pub is_ready: bool
+}
+
+impl IchimokuStrategy {
+    pub fn new(ichimoku: Ichimoku, rule: IchimokuRule) -> Self {
+        Self {
+            ichimoku,
+            rule
+        }
+    }
+}
+
+impl InvestingStrategy<IchimokuStrategyResult> for IchimokuStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> IchimokuStrategyResult {
+        let was_ready = self.ichimoku.is_ready();
+        let yesterday_result = self.ichimoku.current();
+        // Taken from the indicator, the simulator does not always pass yesterday's bar.
+        let yesterday_close = self.ichimoku.last_close();
+        IchimokuStrategyResult {
+            yesterday: yesterday_result,
+            today: self.ichimoku.next(stock_price_info.high, stock_price_info.low, stock_price_info.close),
+            yesterday_close,
+            is_ready: was_ready
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IchimokuStrategyResult) -> Option<Signal> {
+        if !indicator.is_ready {
+            return None
+        }
+        let today = &indicator.today;
+        let close = stock_price_info.close;
+        match self.rule {
+            IchimokuRule::CloudBreakout => {
+                let was_below_top = indicator.yesterday_close.is_some_and(|yesterday_close| yesterday_close <= indicator.yesterday.cloud_top());
+                let chikou_confirms = today.chikou_span > today.chikou_reference_close;
+                if was_below_top && close > today.cloud_top() && chikou_confirms && today.leading_cloud_bullish() {
+                    let cloud_thickness = today.cloud_top() - today.cloud_bottom();
+                    let strength = scaled_strength(close - today.cloud_top(), cloud_thickness);
+                    Some(Signal::new(close, strength, "ichimoku_cloud_breakout"))
+                } else {
+                    None
+                }
+            }
+            IchimokuRule::TkCross => {
+                let crossed_up = indicator.yesterday.tenkan_sen <= indicator.yesterday.kijun_sen && today.tenkan_sen > today.kijun_sen;
+                if crossed_up && close > today.cloud_top() {
+                    Some(Signal::full_strength(close, "ichimoku_tk_cross_above_cloud"))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IchimokuStrategyResult) -> Option<Signal> {
+        if !indicator.is_ready {
+            return None
+        }
+        let today = &indicator.today;
+        match self.rule {
+            IchimokuRule::CloudBreakout if stock_price_info.close < today.cloud_bottom() => {
+                Some(Signal::full_strength(stock_price_info.close, "ichimoku_close_below_cloud"))
+            }
+            IchimokuRule::TkCross if today.tenkan_sen < today.kijun_sen => {
+                Some(Signal::full_strength(stock_price_info.close, "ichimoku_tenkan_below_kijun"))
+            }
+            _ => None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.ichimoku.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::indicator::{Indicator, ScalarOutput};
+
+/// Holds while the `fast` line is above the `slow` one, e.g. the EMA crossover on hl2 or an EMA
+/// of OBV against a slower one. Signal strength is the spread between the lines, at maximum at
+/// `full_scale`: in the lines' own units with `new`, relative to the slow line with `relative`.
+#[derive(Clone)]
+pub struct IndicatorCrossoverStrategy<A, B>
+where
+    A: Indicator,
+    A::Output: ScalarOutput,
+    B: Indicator,
+    B::Output: ScalarOutput
+{
+    fast: A,
+    slow: B,
+    full_scale: f32,
+    relative: bool
+}
+
+#[derive(Clone)]
+pub struct IndicatorCrossoverResult {
+    pub fast: f32,
+    pub slow: f32,
+    pub is_ready: bool
+}
+
+impl IndicatorCrossoverResult {
+    fn spread(&self, relative: bool) -> f32 {
+        if relative {
+            (self.fast - self.slow) / self.slow.abs().max(f32::EPSILON)
+        } else {
+            self.fast - self.slow
+        }
+    }
+}
+
+impl<A, B> IndicatorCrossoverStrategy<A, B>
+where
+    A: Indicator,
+    A::Output: ScalarOutput,
+    B: Indicator,
+    B::Output: ScalarOutput
+{
+    /// For lines crossing zero, such as MACD-style lines or an EMA of OBV.
+    pub fn new(fast: A, slow: B, full_scale: f32) -> Self {
+        Self {
+            fast,
+            slow,
+            full_scale,
+            relative: false
+        }
+    }
+
+    /// For lines staying away from zero, such as price averages, where a spread of e.g. 0.05
+    /// means 5% of the slow line whatever the price.
+    pub fn relative(fast: A, slow: B, full_scale_spread: f32) -> Self {
+        Self {
+            relative: true,
+            ..Self::new(fast, slow, full_scale_spread)
+        }
+    }
+}
+
+impl<A, B> InvestingStrategy<IndicatorCrossoverResult> for IndicatorCrossoverStrategy<A, B>
+where
+    A: Indicator + Clone,
+    A::Output: ScalarOutput,
+    B: Indicator + Clone,
+    B::Output: ScalarOutput
+{
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> IndicatorCrossoverResult {
+        IndicatorCrossoverResult {
+            fast: self.fast.update(stock_price_info).value(),
+            slow: self.slow.update(stock_price_info).value(),
+            is_ready: self.fast.is_ready() && self.slow.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IndicatorCrossoverResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.fast > indicator.slow {
+            let strength = scaled_strength(indicator.spread(self.relative), self.full_scale);
+            Some(Signal::new(stock_price_info.close, strength, "fast_line_above_slow"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IndicatorCrossoverResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.fast < indicator.slow {
+            let strength = scaled_strength(-indicator.spread(self.relative), self.full_scale);
+            Some(Signal::new(stock_price_info.close, strength, "fast_line_below_slow"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::indicator::{Indicator, ScalarOutput};
+
+/// Overbought/oversold rules on the main line of any indicator, typically a sourced or chained
+/// one such as an EMA of RSI: buys below `lower_band` and sells above `higher_band`. Signal
+/// strength reaches its maximum `full_scale` past the band.
+#[derive(Clone)]
+pub struct IndicatorThresholdStrategy<I>
+where
+    I: Indicator,
+    I::Output: ScalarOutput
+{
+    indicator: I,
+    lower_band: f32,
+    higher_band: f32,
+    full_scale: f32,
+    reason: String
+}
+
+#[derive(Clone)]
+pub struct IndicatorThresholdResult {
+    pub value: f32,
+    pub is_ready: bool
+}
+
+impl<I> IndicatorThresholdStrategy<I>
+where
+    I: Indicator,
+    I::Output: ScalarOutput
+{
+    /// `name` prefixes the signal reasons, e.g. "ema_of_rsi" gives "ema_of_rsi_oversold".
+    pub fn new(indicator: I, lower_band: f32, higher_band: f32, full_scale: f32, name: &str) -> Self {
+        Self {
+            indicator,
+            lower_band,
+            higher_band,
+            full_scale,
+            reason: name.to_string()
+        }
+    }
+}
+
+impl<I> InvestingStrategy<IndicatorThresholdResult> for IndicatorThresholdStrategy<I>
+where
+    I: Indicator + Clone,
+    I::Output: ScalarOutput
+{
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> IndicatorThresholdResult {
+        IndicatorThresholdResult {
+            value: self.indicator.update(stock_price_info).value(),
+            is_ready: self.indicator.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IndicatorThresholdResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.value < self.lower_band {
+            let strength = scaled_strength(self.lower_band - indicator.value, self.full_scale);
+            Some(Signal::new(stock_price_info.close, strength, &format!("{}_oversold", self.reason)))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &IndicatorThresholdResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.value > self.higher_band {
+            let strength = scaled_strength(indicator.value - self.higher_band, self.full_scale);
+            Some(Signal::new(stock_price_info.close, strength, &format!("{}_overbought", self.reason)))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 use crate::technical_indicator::keltner_channel::{KeltnerChannel, KeltnerChannelResult};
 
 #[derive(Clone)]
@@ -9,30 +10,41 @@ pub struct KeltnerChannelStrategyResult {
 }
 
 impl InvestingStrategy<KeltnerChannelStrategyResult> for KeltnerChannel {
-    fn calculation(&mut self, today: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> KeltnerChannelStrategyResult {
+    fn calculation(&mut self, today: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> KeltnerChannelStrategyResult {
         KeltnerChannelStrategyResult {
             yesterday: self.current(),
             today: self.next(today.close, today.high, today.low, yesterday.clone().map(|u| u.close).unwrap_or(0.0f32))
         }
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator_data: &KeltnerChannelStrategyResult) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator_data: &KeltnerChannelStrategyResult) -> Option<Signal> {
         let keltner_buy = indicator_data.today.lower_band - stock_price_info.close;
         let signal = keltner_buy;
         if signal > 0.0 {
-            Some(stock_price_info.close)
+            let band_width = indicator_data.today.ema - indicator_data.today.lower_band;
+            Some(Signal::new(stock_price_info.close, scaled_strength(signal, band_width), "close_below_lower_keltner_band"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator_data: &KeltnerChannelStrategyResult) -> Option<f32> {
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator_data: &KeltnerChannelStrategyResult) -> Option<Signal> {
         if indicator_data.today.upper_band <= stock_price_info.high {
-            Some(indicator_data.today.upper_band)
+            let band_width = indicator_data.today.upper_band - indicator_data.today.ema;
+            let strength = scaled_strength(stock_price_info.high - indicator_data.today.upper_band, band_width);
+            Some(Signal::new(indicator_data.today.upper_band, strength, "high_touched_upper_keltner_band"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        KeltnerChannel::reset(self)
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 fn calculate_inclination(yesterday_ema: f32, today_ema: f32) -> f32 {
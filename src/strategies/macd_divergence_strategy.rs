@@ -2,11 +2,13 @@ use std::cmp::min;
 use std::collections::VecDeque;
 
 use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
 use crate::strategies::macd_strategy::MACDStrategy;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 use crate::technical_indicator::macd::{Macd, MACDResult};
 use crate::utils::rolling_window::RollingWindow;
 
+#[derive(Clone)]
 pub struct MACDDivergence {
     macd: Macd,
     last_three_price: RollingWindow<f32>,
@@ -63,6 +65,7 @@ impl MACDDivergence {
 }
 
 
+#[derive(Clone)]
 pub struct MACDDivergenceStrategy {
     macd_divergence: MACDDivergence 
 }
@@ -76,11 +79,11 @@ impl MACDDivergenceStrategy {
 }
 
 impl InvestingStrategy<MACDDivergenceResult> for MACDDivergenceStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> MACDDivergenceResult {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> MACDDivergenceResult {
         self.macd_divergence.next(stock_price_info.close)
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDDivergenceResult) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDDivergenceResult) -> Option<Signal> {
         if indicator.local_minimas.len() > 2 {
             if let (Some(&last_minima), Some(&minima), Some(&last_macd_res), Some(&macd_res)) = (
                 indicator.local_minimas.get(indicator.local_minimas.len() - 1),
@@ -89,7 +92,8 @@ impl InvestingStrategy<MACDDivergenceResult> for MACDDivergenceStrategy {
                 indicator.local_minima_macd.get(indicator.local_minima_macd.len() - 2).clone()
             ) {
                 if minima - last_minima > 2.0 && last_macd_res.macd_line > macd_res.macd_line {
-                    return Some(stock_price_info.close)
+                    let strength = scaled_strength((minima - last_minima) / minima, 0.1);
+                    return Some(Signal::new(stock_price_info.close, strength, "macd_bullish_divergence"))
                 } else {
                     return None
                 }
@@ -101,11 +105,20 @@ impl InvestingStrategy<MACDDivergenceResult> for MACDDivergenceStrategy {
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDDivergenceResult) -> Option<f32> {
-        if indicator.current_macd_result.macd_line > indicator.current_macd_result.signal_line {
-            Some(stock_price_info.close)
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDDivergenceResult) -> Option<Signal> {
+        let macd = indicator.current_macd_result;
+        if macd.macd_line > macd.signal_line {
+            Some(Signal::new(stock_price_info.close, scaled_strength((macd.macd_line - macd.signal_line) / stock_price_info.close, 0.01), "macd_above_signal"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        self.macd_divergence = MACDDivergence::new()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -1,44 +1,64 @@
 use crate::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
 use crate::technical_indicator::ema::Ema;
 use crate::technical_indicator::macd::{Macd, MACDResult};
+use crate::technical_indicator::price_source::PriceSource;
 
+#[derive(Clone)]
 pub struct MACDStrategy {
-    macd: Macd
+    macd: Macd,
+    source: PriceSource
 }
 
 impl MACDStrategy {
 
     pub fn new(slow_period:usize, fast_period: usize, signal_period: usize) -> Self {
+        Self::with_source(slow_period, fast_period, signal_period, PriceSource::Close)
+    }
+
+    pub fn with_source(slow_period:usize, fast_period: usize, signal_period: usize, source: PriceSource) -> Self {
         Self {
-            macd: Macd::new(slow_period, fast_period, signal_period)
+            macd: Macd::new(slow_period, fast_period, signal_period),
+            source
         }
     }
 
     pub fn default() -> Self {
         MACDStrategy {
-            macd: Macd::default()
+            macd: Macd::default(),
+            source: PriceSource::Close
         }
     }
 }
 impl InvestingStrategy<MACDResult> for MACDStrategy {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>) -> MACDResult {
-        self.macd.next(stock_price_info.close)
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> MACDResult {
+        self.macd.next(self.source.value(stock_price_info))
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDResult) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDResult) -> Option<Signal> {
         if indicator.macd_line > indicator.signal_line {
-            Some(stock_price_info.close)
+            let strength = scaled_strength((indicator.macd_line - indicator.signal_line) / stock_price_info.close, 0.01);
+            Some(Signal::new(stock_price_info.close, strength, "macd_above_signal"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDResult) -> Option<f32> {
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MACDResult) -> Option<Signal> {
         if indicator.macd_line < indicator.signal_line {
-            Some(stock_price_info.close)
+            let strength = scaled_strength((indicator.signal_line - indicator.macd_line) / stock_price_info.close, 0.01);
+            Some(Signal::new(stock_price_info.close, strength, "macd_below_signal"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        self.macd.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+
+/// Wraps any strategy and lets its buy signals through only while the benchmark closes above
+/// its EMA, e.g. "only buy when the NASDAQ-100 is above its 200-day EMA". No buy gets through
+/// before the EMA has seen `benchmark_ema_length` benchmark closes.
+pub struct MarketRegimeFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    strategy: S,
+    benchmark_ema: Ema,
+    sell_on_bear_regime: bool,
+    _phantom: PhantomData<T>
+}
+
+#[derive(Clone)]
+pub struct MarketRegimeResult<T> {
+    pub strategy_result: T,
+    pub benchmark_close: Option<f32>,
+    pub benchmark_ema: f32,
+    pub benchmark_ema_ready: bool
+}
+
+impl<T> MarketRegimeResult<T> {
+    fn is_bull_regime(&self) -> bool {
+        self.benchmark_ema_ready && self.benchmark_close.is_some_and(|close| close > self.benchmark_ema)
+    }
+}
+
+impl<T, S> MarketRegimeFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    pub fn new(strategy: S, benchmark_ema_length: usize) -> Self {
+        Self {
+            strategy,
+            benchmark_ema: Ema::with_seed(benchmark_ema_length, EmaSeed::Sma),
+            sell_on_bear_regime: false,
+            _phantom: PhantomData
+        }
+    }
+
+    /// Same as `new`, but additionally closes open positions as soon as the benchmark drops below
+    /// its EMA, once the EMA is ready.
+    pub fn with_sell_on_bear_regime(strategy: S, benchmark_ema_length: usize) -> Self {
+        Self {
+            sell_on_bear_regime: true,
+            ..Self::new(strategy, benchmark_ema_length)
+        }
+    }
+}
+
+impl<T, S> InvestingStrategy<MarketRegimeResult<T>> for MarketRegimeFilter<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, context: &StrategyContext) -> MarketRegimeResult<T> {
+        let benchmark_close = context.benchmark.as_ref().map(|benchmark| benchmark.close);
+        if let Some(close) = benchmark_close {
+            self.benchmark_ema.next(close);
+        }
+        MarketRegimeResult {
+            strategy_result: self.strategy.calculation(stock_price_info, yesterday, context),
+            benchmark_close,
+            benchmark_ema: self.benchmark_ema.current(),
+            benchmark_ema_ready: self.benchmark_ema.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MarketRegimeResult<T>) -> Option<Signal> {
+        if indicator.is_bull_regime() {
+            self.strategy.buy_signal(stock_price_info, &indicator.strategy_result)
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &MarketRegimeResult<T>) -> Option<Signal> {
+        let strategy_signal = self.strategy.sell_signal(stock_price_info, &indicator.strategy_result);
+        if strategy_signal.is_none() && self.sell_on_bear_regime && indicator.benchmark_ema_ready && !indicator.is_bull_regime() {
+            Some(Signal::full_strength(stock_price_info.close, "benchmark_below_ema"))
+        } else {
+            strategy_signal
+        }
+    }
+
+    fn reset(&mut self) {
+        self.strategy.reset();
+        self.benchmark_ema.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(Self {
+            strategy: self.strategy.fork()?,
+            benchmark_ema: self.benchmark_ema.clone(),
+            sell_on_bear_regime: self.sell_on_bear_regime,
+            _phantom: PhantomData
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::mfi::Mfi;
+
+#[derive(Clone)]
+pub struct MfiStrategy {
+    mfi: Mfi,
+    lower_band: f32,
+    higher_band: f32
+}
+
+impl MfiStrategy {
+    pub fn new(length: usize, lower_band: f32, higher_band: f32) -> Self {
+        MfiStrategy {
+            mfi: Mfi::new(length),
+            lower_band,
+            higher_band
+        }
+    }
+}
+
+impl InvestingStrategy<f32> for MfiStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> f32 {
+        self.mfi.next(stock_price_info.high, stock_price_info.low, stock_price_info.close, stock_price_info.vol)
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.mfi.is_ready() && *indicator < self.lower_band {
+            let strength = scaled_strength(self.lower_band - *indicator, self.lower_band);
+            Some(Signal::new(stock_price_info.close, strength, "mfi_oversold"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.mfi.is_ready() && *indicator > self.higher_band {
+            let strength = scaled_strength(*indicator - self.higher_band, 100.0 - self.higher_band);
+            Some(Signal::new(stock_price_info.close, strength, "mfi_overbought"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.mfi.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -5,4 +5,31 @@ pub mod macd_strategy;
 pub mod macd_divergence_strategy;
 pub mod arima;
 pub mod ema_long_term_trend;
-pub mod ema_crossover_strategy;
\ No newline at end of file
+pub mod ema_crossover_strategy;
+pub mod higher_timeframe_trend_strategy;
+pub mod market_regime_filter;
+pub mod drawdown_mean_reversion_strategy;
+pub mod divergence_strategy;
+pub mod calendar_strategy;
+pub mod candlestick_pattern_strategy;
+pub mod onnx_model_strategy;
+pub mod wasm_plugin_strategy;
+pub mod remote_strategy;
+#[cfg(test)]
+pub mod stand_in_signal_server;
+pub mod turtle_strategy;
+pub mod bollinger_mean_reversion_strategy;
+pub mod bollinger_squeeze_strategy;
+pub mod stochastic_strategy;
+pub mod williams_r_strategy;
+pub mod cci_strategy;
+pub mod mfi_strategy;
+pub mod adx_strategy;
+pub mod aroon_strategy;
+pub mod parabolic_sar_strategy;
+pub mod supertrend_strategy;
+pub mod adx_trend_filter;
+pub mod volume_confirmation;
+pub mod ichimoku_strategy;
+pub mod indicator_threshold_strategy;
+pub mod indicator_crossover_strategy;
\ No newline at end of file
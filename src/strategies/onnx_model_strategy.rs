@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+use tract_onnx::prelude::*;
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::feature_pipeline::{Feature, FeaturePipeline};
+
+pub type OnnxModel = TypedRunnableModel<TypedModel>;
+
+/// Maps the classifier output onto trades: buy when the probability of the "up" class reaches
+/// `buy_threshold`, sell when it falls to `sell_threshold`.
+#[derive(Clone, Copy)]
+pub struct ProbabilityThresholds {
+    /// Model output holding the class probabilities, e.g. 1 for skl2onnx classifiers exported with `zipmap=False`.
+    pub output_index: usize,
+    pub up_class_index: usize,
+    pub buy_threshold: f32,
+    pub sell_threshold: f32
+}
+
+/// Runs an offline trained ONNX classifier on CPU with tract, feeding it a `[1, features]` f32
+/// tensor built from the indicator feature pipeline every bar.
+#[derive(Clone)]
+pub struct OnnxModelStrategy {
+    model: Arc<OnnxModel>,
+    feature_pipeline: FeaturePipeline,
+    thresholds: ProbabilityThresholds,
+    failure: Option<String>
+}
+
+#[derive(Clone)]
+pub struct OnnxModelResult {
+    pub features: Vec<f32>,
+    /// `None` until every feature indicator is warmed up, and after the model failed.
+    pub up_probability: Option<f32>
+}
+
+impl OnnxModelStrategy {
+    pub fn load_model(model_path: &Path, number_of_features: usize) -> Result<Arc<OnnxModel>, Box<dyn Error>> {
+        let model = tract_onnx::onnx()
+            .model_for_path(model_path)?
+            .with_input_fact(0, f32::fact([1, number_of_features]).into())?
+            .into_optimized()?
+            .into_runnable()?;
+        Ok(Arc::new(model))
+    }
+
+    pub fn from_file(model_path: &Path, features: &[Feature], thresholds: ProbabilityThresholds) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::new(Self::load_model(model_path, features.len())?, features, thresholds))
+    }
+
+    /// Shares an already loaded model, so it is parsed and optimised once for all tickers.
+    pub fn new(model: Arc<OnnxModel>, features: &[Feature], thresholds: ProbabilityThresholds) -> Self {
+        Self {
+            model,
+            feature_pipeline: FeaturePipeline::new(features),
+            thresholds,
+            failure: None
+        }
+    }
+
+    /// The error that stopped the model, e.g. an output shape not matching the thresholds. A
+    /// failed model produces no further signals.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    fn up_probability(&self, features: &[f32]) -> Result<f32, Box<dyn Error>> {
+        let input = Tensor::from_shape(&[1, features.len()], features)?;
+        let outputs = self.model.run(tvec!(input.into()))?;
+        let probabilities = outputs.get(self.thresholds.output_index)
+            .ok_or(format!("model has no output {}", self.thresholds.output_index))?
+            .cast_to::<f32>()?
+            .into_owned();
+        let up_probability = probabilities.as_slice::<f32>()?
+            .get(self.thresholds.up_class_index)
+            .cloned()
+            .ok_or(format!("model output has no class {}", self.thresholds.up_class_index))?;
+        Ok(up_probability)
+    }
+}
+
+impl InvestingStrategy<OnnxModelResult> for OnnxModelStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> OnnxModelResult {
+        let features = self.feature_pipeline.next(stock_price_info, yesterday);
+        let up_probability = if self.feature_pipeline.is_ready() && self.failure.is_none() {
+            match self.up_probability(&features) {
+                Ok(up_probability) => Some(up_probability),
+                Err(error) => {
+                    self.failure = Some(format!("{}: {}", stock_price_info.date, error));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        OnnxModelResult {
+            features,
+            up_probability
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &OnnxModelResult) -> Option<Signal> {
+        match indicator.up_probability {
+            Some(probability) if probability >= self.thresholds.buy_threshold => {
+                let strength = scaled_strength(probability - self.thresholds.buy_threshold, 1.0 - self.thresholds.buy_threshold);
+                Some(Signal::new(stock_price_info.close, strength, "model_up_probability_above_threshold"))
+            }
+            _ => None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &OnnxModelResult) -> Option<Signal> {
+        match indicator.up_probability {
+            Some(probability) if probability <= self.thresholds.sell_threshold => {
+                let strength = scaled_strength(self.thresholds.sell_threshold - probability, self.thresholds.sell_threshold);
+                Some(Signal::new(stock_price_info.close, strength, "model_up_probability_below_threshold"))
+            }
+            _ => None
+        }
+    }
+
+    /// Also clears a previous failure.
+    fn reset(&mut self) {
+        self.feature_pipeline.reset();
+        self.failure = None;
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
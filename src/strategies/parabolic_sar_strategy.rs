@@ -0,0 +1,49 @@
+use crate::StockPriceInfo;
+use crate::signal::Signal;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::parabolic_sar::{ParabolicSar, ParabolicSarResult};
+
+/// Buys at the close of a reversal into an uptrend and uses the SAR as trailing stop: the
+/// position is sold at the stop when the low crosses it.
+#[derive(Clone)]
+pub struct ParabolicSarStrategy {
+    parabolic_sar: ParabolicSar
+}
+
+impl ParabolicSarStrategy {
+    pub fn new(acceleration_step: f32, max_acceleration: f32) -> Self {
+        Self {
+            parabolic_sar: ParabolicSar::new(acceleration_step, max_acceleration)
+        }
+    }
+}
+
+impl InvestingStrategy<ParabolicSarResult> for ParabolicSarStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> ParabolicSarResult {
+        self.parabolic_sar.next(stock_price_info.high, stock_price_info.low)
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &ParabolicSarResult) -> Option<Signal> {
+        if indicator.reversed && indicator.is_uptrend {
+            Some(Signal::full_strength(stock_price_info.close, "parabolic_sar_reversed_up"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &ParabolicSarResult) -> Option<Signal> {
+        if indicator.reversed && !indicator.is_uptrend {
+            Some(Signal::full_strength(f32::min(stock_price_info.open, indicator.stop_price), "parabolic_sar_stop"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.parabolic_sar.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::error::Error;
+use tokio::runtime::Runtime;
+use tonic::transport::Channel;
+use crate::signal::Signal;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::strategies::remote_strategy::signal_service::signal_service_client::SignalServiceClient;
+use crate::strategies::remote_strategy::signal_service::{Bar, BarRequest, CloseSessionRequest, OpenSessionRequest, TradeSignal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+
+pub mod signal_service {
+    tonic::include_proto!("signal_service");
+}
+
+/// Strategy computed by an external `SignalService` server, e.g. a Python model server. A session
+/// is opened per ticker on connect and closed on drop; every bar is sent to the server and the
+/// returned signals are executed as they come.
+pub struct RemoteStrategy {
+    tokyo_runtime: Runtime,
+    client: SignalServiceClient<Channel>,
+    ticker: String,
+    parameters: HashMap<String, String>,
+    session_id: String,
+    failure: Option<String>
+}
+
+#[derive(Clone, Default)]
+pub struct RemoteStrategyResult {
+    pub buy: Option<Signal>,
+    pub sell: Option<Signal>,
+    pub indicators: HashMap<String, f32>
+}
+
+impl RemoteStrategy {
+    pub fn connect(endpoint: &str, ticker: &str, parameters: HashMap<String, String>) -> Result<RemoteStrategy, Box<dyn Error>> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        let mut client = runtime.block_on(SignalServiceClient::connect(endpoint.to_string()))?;
+        let session_id = runtime.block_on(open_session(&mut client, ticker, &parameters))?;
+
+        Ok(Self {
+            tokyo_runtime: runtime,
+            client,
+            ticker: ticker.to_string(),
+            parameters,
+            session_id,
+            failure: None
+        })
+    }
+
+    /// The error that stopped the strategy, e.g. the server going away. A failed strategy
+    /// produces no further signals.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+}
+
+async fn open_session(client: &mut SignalServiceClient<Channel>, ticker: &str, parameters: &HashMap<String, String>) -> Result<String, tonic::Status> {
+    let session = client.open_session(OpenSessionRequest {
+        ticker: ticker.to_string(),
+        parameters: parameters.clone()
+    }).await?;
+    Ok(session.into_inner().session_id)
+}
+
+impl Drop for RemoteStrategy {
+    fn drop(&mut self) {
+        let _ = self.tokyo_runtime.block_on(
+            self.client.close_session(CloseSessionRequest {
+                session_id: self.session_id.clone()
+            })
+        );
+    }
+}
+
+impl From<&StockPriceInfo> for Bar {
+    fn from(stock_price_info: &StockPriceInfo) -> Self {
+        Self {
+            ticker: stock_price_info.ticker.clone(),
+            per: stock_price_info.per.clone(),
+            date: stock_price_info.date.format("%Y%m%d").to_string(),
+            time: stock_price_info.time.clone(),
+            open: stock_price_info.open,
+            high: stock_price_info.high,
+            low: stock_price_info.low,
+            close: stock_price_info.close,
+            vol: stock_price_info.vol,
+            openint: stock_price_info.openint
+        }
+    }
+}
+
+fn to_signal(trade_signal: TradeSignal, stock_price_info: &StockPriceInfo) -> Signal {
+    let price = if trade_signal.price > 0.0 { trade_signal.price } else { stock_price_info.close };
+    Signal::new(price, trade_signal.strength, &trade_signal.reason)
+}
+
+impl InvestingStrategy<RemoteStrategyResult> for RemoteStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> RemoteStrategyResult {
+        if self.failure.is_some() {
+            return RemoteStrategyResult::default();
+        }
+        let res = self.tokyo_runtime.block_on(
+            self.client.on_bar(BarRequest {
+                session_id: self.session_id.clone(),
+                bar: Some(stock_price_info.into())
+            })
+        );
+        let response = match res {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                self.failure = Some(format!("{}: {}", stock_price_info.date, status));
+                return RemoteStrategyResult::default();
+            }
+        };
+
+        RemoteStrategyResult {
+            buy: response.buy.map(|signal| to_signal(signal, stock_price_info)),
+            sell: response.sell.map(|signal| to_signal(signal, stock_price_info)),
+            indicators: response.indicators
+        }
+    }
+
+    fn buy_signal(&self, _: &StockPriceInfo, indicator: &RemoteStrategyResult) -> Option<Signal> {
+        indicator.buy.clone()
+    }
+
+    fn sell_signal(&self, _: &StockPriceInfo, indicator: &RemoteStrategyResult) -> Option<Signal> {
+        indicator.sell.clone()
+    }
+
+    /// Replaces the session with a new one, the server drops whatever it kept for the old one.
+    /// Also clears a previous failure once the new session is open.
+    fn reset(&mut self) {
+        let _ = self.tokyo_runtime.block_on(
+            self.client.close_session(CloseSessionRequest {
+                session_id: self.session_id.clone()
+            })
+        );
+        match self.tokyo_runtime.block_on(open_session(&mut self.client, &self.ticker, &self.parameters)) {
+            Ok(session_id) => {
+                self.session_id = session_id;
+                self.failure = None;
+            }
+            Err(status) => self.failure = Some(format!("reset: {}", status))
+        }
+    }
+
+    /// Session state lives in the server and the protocol has no way to copy it.
+    fn fork(&self) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::strategies::stand_in_signal_server::StandInSignalServer;
+    use super::*;
+
+    fn bar(day: u32, close: f32) -> StockPriceInfo {
+        StockPriceInfo {
+            ticker: "AAPL.US".to_string(),
+            per: "D".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+            time: "000000".to_string(),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            vol: 1000.0,
+            openint: 0
+        }
+    }
+
+    /// Feeds the closes one by one and returns the reasons of the buy and sell signals per bar.
+    fn drive(strategy: &mut RemoteStrategy, first_day: u32, closes: &[f32]) -> Vec<(Option<String>, Option<String>)> {
+        closes.iter().enumerate().map(|(i, &close)| {
+            let stock_price_info = bar(first_day + i as u32, close);
+            let result = strategy.calculation(&stock_price_info, &None, &StrategyContext::default());
+            (strategy.buy_signal(&stock_price_info, &result).map(|signal| signal.reason),
+             strategy.sell_signal(&stock_price_info, &result).map(|signal| signal.reason))
+        }).collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn executes_stand_in_signals_and_records_server_failures() {
+        let server = StandInSignalServer::start(3).await.unwrap();
+        let endpoint = server.endpoint();
+
+        // RemoteStrategy blocks on its own runtime, so it runs outside of the test's async context.
+        let (mut strategy, signals) = tokio::task::spawn_blocking(move || {
+            let mut strategy = RemoteStrategy::connect(&endpoint, "aapl.us", HashMap::new()).unwrap();
+            let signals = drive(&mut strategy, 1, &[10.0, 11.0, 12.0, 9.0]);
+            (strategy, signals)
+        }).await.unwrap();
+
+        let reason = Some("stand_in_moving_average".to_string());
+        assert_eq!(signals, vec![
+            (None, None),
+            (None, None),
+            (reason.clone(), None),
+            (None, reason)
+        ]);
+        assert!(strategy.failure().is_none());
+
+        server.stop();
+        let strategy = tokio::task::spawn_blocking(move || {
+            assert_eq!(drive(&mut strategy, 5, &[15.0]), vec![(None, None)]);
+            assert!(strategy.failure().is_some_and(|failure| failure.starts_with("2024-01-05")));
+            strategy
+        }).await.unwrap();
+        tokio::task::spawn_blocking(move || drop(strategy)).await.unwrap();
+    }
+}
@@ -1,42 +1,61 @@
 use crate::StockPriceInfo;
-use crate::strategy_simulator::InvestingStrategy;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::price_source::PriceSource;
 use crate::technical_indicator::rsi::{Rsi, RsiResult};
 
+#[derive(Clone)]
 pub struct RsiStrategy {
     rsi: Rsi,
     lower_band: f32,
     higher_band: f32,
+    source: PriceSource
 }
 
 impl RsiStrategy {
     pub fn new(length: usize, lower_band: f32, higher_band: f32) -> Self {
+        Self::with_source(length, lower_band, higher_band, PriceSource::Close)
+    }
+
+    pub fn with_source(length: usize, lower_band: f32, higher_band: f32, source: PriceSource) -> Self {
         RsiStrategy {
             rsi: Rsi::new(length),
             lower_band,
-            higher_band
+            higher_band,
+            source
         }
     }
 }
 
 impl InvestingStrategy<RsiResult> for RsiStrategy {
 
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> RsiResult {
-        self.rsi.next(stock_price_info.close)
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, _: &StrategyContext) -> RsiResult {
+        self.rsi.next(self.source.value(stock_price_info))
     }
 
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &RsiResult) -> Option<f32> {
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &RsiResult) -> Option<Signal> {
         if indicator.rsi_line < self.lower_band {
-            Some(stock_price_info.close)
+            let strength = scaled_strength(self.lower_band - indicator.rsi_line, self.lower_band);
+            Some(Signal::new(stock_price_info.close, strength, "rsi_oversold"))
         } else {
             None
         }
     }
 
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &RsiResult) -> Option<f32> {
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &RsiResult) -> Option<Signal> {
         if indicator.rsi_line > self.higher_band {
-            Some(stock_price_info.close)
+            let strength = scaled_strength(indicator.rsi_line - self.higher_band, 100.0 - self.higher_band);
+            Some(Signal::new(stock_price_info.close, strength, "rsi_overbought"))
         } else {
             None
         }
     }
+
+    fn reset(&mut self) {
+        self.rsi.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::oneshot;
+use tonic::{Request, Response, Status};
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use crate::strategies::remote_strategy::signal_service::signal_service_server::{SignalService, SignalServiceServer};
+use crate::strategies::remote_strategy::signal_service::{BarRequest, BarResponse, CloseSessionRequest, CloseSessionResponse, OpenSessionRequest, OpenSessionResponse, TradeSignal};
+
+/// Moving average rule behind the stand-in server: buy when the close is above the average of the
+/// last `lookback` closes of the session, sell when below.
+struct MovingAverageSignalService {
+    lookback: usize,
+    sessions: Mutex<HashMap<String, Vec<f32>>>,
+    opened_sessions: Mutex<usize>,
+    available: Arc<AtomicBool>
+}
+
+impl MovingAverageSignalService {
+    fn is_stopped(&self) -> bool {
+        !self.available.load(Ordering::SeqCst)
+    }
+}
+
+fn stopped() -> Status {
+    Status::unavailable("stand-in server stopped")
+}
+
+#[tonic::async_trait]
+impl SignalService for MovingAverageSignalService {
+    async fn open_session(&self, request: Request<OpenSessionRequest>) -> Result<Response<OpenSessionResponse>, Status> {
+        if self.is_stopped() {
+            return Err(stopped());
+        }
+        let mut opened_sessions = self.opened_sessions.lock().unwrap();
+        *opened_sessions += 1;
+        let session_id = format!("{}-{}", request.into_inner().ticker, opened_sessions);
+        self.sessions.lock().unwrap().insert(session_id.clone(), vec![]);
+        Ok(Response::new(OpenSessionResponse { session_id }))
+    }
+
+    async fn on_bar(&self, request: Request<BarRequest>) -> Result<Response<BarResponse>, Status> {
+        if self.is_stopped() {
+            return Err(stopped());
+        }
+        let request = request.into_inner();
+        let bar = request.bar.ok_or_else(|| Status::invalid_argument("missing bar"))?;
+        let mut sessions = self.sessions.lock().unwrap();
+        let closes = sessions.get_mut(&request.session_id)
+            .ok_or_else(|| Status::not_found(format!("unknown session {}", request.session_id)))?;
+        closes.push(bar.close);
+
+        let mut response = BarResponse::default();
+        if closes.len() >= self.lookback {
+            let average = closes[closes.len() - self.lookback..].iter().sum::<f32>() / self.lookback as f32;
+            response.indicators.insert("moving_average".to_string(), average);
+            let signal = TradeSignal {
+                price: 0.0,
+                strength: 1.0,
+                reason: "stand_in_moving_average".to_string()
+            };
+            if bar.close > average {
+                response.buy = Some(signal)
+            } else if bar.close < average {
+                response.sell = Some(signal)
+            }
+        }
+        Ok(Response::new(response))
+    }
+
+    async fn close_session(&self, request: Request<CloseSessionRequest>) -> Result<Response<CloseSessionResponse>, Status> {
+        if self.is_stopped() {
+            return Err(stopped());
+        }
+        let session_id = request.into_inner().session_id;
+        self.sessions.lock().unwrap().remove(&session_id)
+            .ok_or_else(|| Status::not_found(format!("unknown session {}", session_id)))?;
+        Ok(Response::new(CloseSessionResponse {}))
+    }
+}
+
+/// In-process `SignalService` on a local port, running on the caller's tokio runtime, to test
+/// `RemoteStrategy` without an external model server.
+pub struct StandInSignalServer {
+    address: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    available: Arc<AtomicBool>
+}
+
+impl StandInSignalServer {
+    pub async fn start(lookback: usize) -> io::Result<StandInSignalServer> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let incoming = TcpIncoming::from_listener(listener, true, None)
+            .map_err(io::Error::other)?;
+        let (shutdown, shutdown_received) = oneshot::channel::<()>();
+        let available = Arc::new(AtomicBool::new(true));
+        let service = MovingAverageSignalService {
+            lookback,
+            sessions: Mutex::new(HashMap::new()),
+            opened_sessions: Mutex::new(0),
+            available: available.clone()
+        };
+
+        tokio::spawn(async move {
+            Server::builder()
+                .add_service(SignalServiceServer::new(service))
+                .serve_with_incoming_shutdown(incoming, async {
+                    let _ = shutdown_received.await;
+                })
+                .await
+                .unwrap()
+        });
+
+        Ok(Self {
+            address,
+            shutdown,
+            available
+        })
+    }
+
+    pub fn endpoint(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Fails every further request as unavailable, the way a client sees a server going away,
+    /// and shuts the server down. Open connections are not waited for: a `RemoteStrategy` only
+    /// reads from its connection while it sends a bar.
+    pub fn stop(self) {
+        self.available.store(false, Ordering::SeqCst);
+        let _ = self.shutdown.send(());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::stochastic::{Stochastic, StochasticResult};
+
+/// Buys when %K crosses above %D below `lower_band` and sells when it crosses below %D above
+/// `higher_band`.
+#[derive(Clone)]
+pub struct StochasticStrategy {
+    stochastic: Stochastic,
+    lower_band: f32,
+    higher_band: f32
+}
+
+#[derive(Clone)]
+pub struct StochasticStrategyResult {
+    pub yesterday: StochasticResult,
+    pub today: StochasticResult,
+    pub is_ready: bool
+}
+
+impl StochasticStrategy {
+    pub fn new(stochastic: Stochastic, lower_band: f32, higher_band: f32) -> Self {
+        StochasticStrategy {
+            stochastic,
+            lower_band,
+            higher_band
+        }
+    }
+}
+
+impl InvestingStrategy<StochasticStrategyResult> for StochasticStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> StochasticStrategyResult {
+        let is_ready = self.stochastic.is_ready();
+        StochasticStrategyResult {
+            yesterday: self.stochastic.current(),
+            today: self.stochastic.next(stock_price_info.high, stock_price_info.low, stock_price_info.close),
+            is_ready
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &StochasticStrategyResult) -> Option<Signal> {
+        let crossed_up = indicator.yesterday.k <= indicator.yesterday.d && indicator.today.k > indicator.today.d;
+        if indicator.is_ready && crossed_up && indicator.today.d < self.lower_band {
+            let strength = scaled_strength(self.lower_band - indicator.today.d, self.lower_band);
+            Some(Signal::new(stock_price_info.close, strength, "stochastic_oversold_cross"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &StochasticStrategyResult) -> Option<Signal> {
+        let crossed_down = indicator.yesterday.k >= indicator.yesterday.d && indicator.today.k < indicator.today.d;
+        if indicator.is_ready && crossed_down && indicator.today.d > self.higher_band {
+            let strength = scaled_strength(indicator.today.d - self.higher_band, 100.0 - self.higher_band);
+            Some(Signal::new(stock_price_info.close, strength, "stochastic_overbought_cross"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.stochastic.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
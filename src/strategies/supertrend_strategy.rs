@@ -0,0 +1,58 @@
+use crate::StockPriceInfo;
+use crate::signal::Signal;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::supertrend::{Supertrend, SupertrendResult};
+
+/// Buys when the close flips Supertrend to an uptrend and sells when a close below the trailing
+/// lower band flips it back.
+#[derive(Clone)]
+pub struct SupertrendStrategy {
+    supertrend: Supertrend
+}
+
+#[derive(Clone)]
+pub struct SupertrendStrategyResult {
+    pub supertrend: SupertrendResult,
+    pub is_ready: bool
+}
+
+impl SupertrendStrategy {
+    pub fn new(atr_length: usize, multiplier: f32) -> Self {
+        Self {
+            supertrend: Supertrend::new(atr_length, multiplier)
+        }
+    }
+}
+
+impl InvestingStrategy<SupertrendStrategyResult> for SupertrendStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> SupertrendStrategyResult {
+        SupertrendStrategyResult {
+            supertrend: self.supertrend.next(stock_price_info.high, stock_price_info.low, stock_price_info.close),
+            is_ready: self.supertrend.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &SupertrendStrategyResult) -> Option<Signal> {
+        if indicator.is_ready && indicator.supertrend.flipped && indicator.supertrend.is_uptrend {
+            Some(Signal::full_strength(stock_price_info.close, "supertrend_flipped_up"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &SupertrendStrategyResult) -> Option<Signal> {
+        if indicator.is_ready && !indicator.supertrend.is_uptrend {
+            Some(Signal::full_strength(stock_price_info.close, "close_below_supertrend"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.supertrend.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::signal::{scaled_strength, Signal};
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::atr::Atr;
+use crate::technical_indicator::donchian_channel::DonchianChannel;
+use crate::technical_indicator::indicator::Indicator;
+
+/// Breakout and exit channel lengths of one of the original turtle systems. Only the long side
+/// is traded.
+#[derive(Clone, Copy, Debug)]
+pub struct TurtleSystem {
+    pub entry_length: usize,
+    pub exit_length: usize,
+    /// Skip a breakout when the previous breakout, taken or not, would have been a winner.
+    pub skip_after_winner: bool,
+    /// Longer breakout that is taken even when the regular one was skipped.
+    pub failsafe_entry_length: Option<usize>
+}
+
+impl TurtleSystem {
+    /// 20-day breakout, 10-day exit, skip after a winner with a 55-day failsafe.
+    pub fn system_1() -> Self {
+        Self {
+            entry_length: 20,
+            exit_length: 10,
+            skip_after_winner: true,
+            failsafe_entry_length: Some(55)
+        }
+    }
+
+    /// 55-day breakout, 20-day exit, every breakout is taken.
+    pub fn system_2() -> Self {
+        Self {
+            entry_length: 55,
+            exit_length: 20,
+            skip_after_winner: false,
+            failsafe_entry_length: None
+        }
+    }
+}
+
+/// Breakout that is followed as if it was taken, to know whether the next one should be skipped.
+#[derive(Clone, Copy)]
+struct BreakoutTrade {
+    entry_price: f32,
+    stop_price: f32
+}
+
+/// Turtle entries and exits for a single position: buys as soon as the high breaks above the
+/// entry channel of the previous days and sells on a break below the exit channel or at the 2N
+/// stop, N being the 20-day ATR. Unit sizing and pyramiding need their own accounting, see
+/// `TurtleTrading`.
+#[derive(Clone)]
+pub struct TurtleStrategy {
+    system: TurtleSystem,
+    entry_channel: DonchianChannel,
+    exit_channel: DonchianChannel,
+    failsafe_channel: Option<DonchianChannel>,
+    atr: Atr,
+    stop_multiple: f32,
+    last_breakout: Option<BreakoutTrade>,
+    last_breakout_winner: bool,
+    position: Option<BreakoutTrade>
+}
+
+#[derive(Clone)]
+pub struct TurtleResult {
+    /// ATR known before today's bar.
+    pub n: f32,
+    /// Highest high of the entry channel before today.
+    pub entry_channel_high: f32,
+    /// Lowest low of the exit channel before today.
+    pub exit_channel_low: f32,
+    /// Fill price of a breakout the system takes today.
+    pub entry_price: Option<f32>,
+    pub skipped_breakout: bool,
+    /// Fill price when today breaks below the exit channel.
+    pub channel_exit_price: Option<f32>,
+    /// Fill price when the single position's 2N stop is hit today.
+    pub stop_price: Option<f32>
+}
+
+impl TurtleStrategy {
+    pub fn new(system: TurtleSystem) -> Self {
+        Self {
+            system,
+            entry_channel: DonchianChannel::new(system.entry_length),
+            exit_channel: DonchianChannel::new(system.exit_length),
+            failsafe_channel: system.failsafe_entry_length.map(DonchianChannel::new),
+            atr: Atr::new(20),
+            stop_multiple: 2.0,
+            last_breakout: None,
+            last_breakout_winner: false,
+            position: None
+        }
+    }
+
+    fn close_last_breakout(&mut self, today: &StockPriceInfo, channel_exit_price: Option<f32>) {
+        if let Some(breakout) = self.last_breakout {
+            let exit_price = if today.low <= breakout.stop_price {
+                Some(f32::min(today.open, breakout.stop_price))
+            } else {
+                channel_exit_price
+            };
+            if let Some(exit_price) = exit_price {
+                self.last_breakout_winner = exit_price > breakout.entry_price;
+                self.last_breakout = None;
+            }
+        }
+    }
+}
+
+fn breakout_price(today: &StockPriceInfo, channel_high: f32) -> Option<f32> {
+    if today.high > channel_high {
+        Some(f32::max(today.open, channel_high))
+    } else {
+        None
+    }
+}
+
+impl InvestingStrategy<TurtleResult> for TurtleStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> TurtleResult {
+        let n = self.atr.current();
+        // Without a full N the 2N stop would be too tight.
+        let entry_ready = self.entry_channel.is_ready() && self.atr.is_ready();
+        let entry_channel_high = self.entry_channel.current().upper_band;
+        let exit_channel_low = self.exit_channel.current().lower_band;
+        let channel_exit_price = if self.exit_channel.is_ready() && stock_price_info.low < exit_channel_low {
+            Some(f32::min(stock_price_info.open, exit_channel_low))
+        } else {
+            None
+        };
+        let failsafe_price = self.failsafe_channel.as_ref()
+            .filter(|channel| entry_ready && channel.is_ready())
+            .and_then(|channel| breakout_price(stock_price_info, channel.current().upper_band));
+
+        let stop_price = self.position
+            .filter(|position| stock_price_info.low <= position.stop_price)
+            .map(|position| f32::min(stock_price_info.open, position.stop_price));
+        if stop_price.is_some() || channel_exit_price.is_some() {
+            self.position = None;
+        }
+        self.close_last_breakout(stock_price_info, channel_exit_price);
+
+        let mut entry_price = None;
+        let mut skipped_breakout = false;
+        if let Some(price) = breakout_price(stock_price_info, entry_channel_high).filter(|_| entry_ready && self.last_breakout.is_none()) {
+            let skip = self.system.skip_after_winner && self.last_breakout_winner;
+            self.last_breakout = Some(BreakoutTrade { entry_price: price, stop_price: price - self.stop_multiple * n });
+            skipped_breakout = skip;
+            entry_price = if skip { failsafe_price } else { Some(price) };
+        } else if self.position.is_none() && self.last_breakout.is_some() {
+            entry_price = failsafe_price;
+        }
+        if self.position.is_none() {
+            self.position = entry_price.map(|price| BreakoutTrade { entry_price: price, stop_price: price - self.stop_multiple * n });
+        }
+
+        self.atr.update(stock_price_info);
+        self.entry_channel.next(stock_price_info.high, stock_price_info.low);
+        self.exit_channel.next(stock_price_info.high, stock_price_info.low);
+        if let Some(failsafe_channel) = self.failsafe_channel.as_mut() {
+            failsafe_channel.next(stock_price_info.high, stock_price_info.low);
+        }
+
+        TurtleResult {
+            n,
+            entry_channel_high,
+            exit_channel_low,
+            entry_price,
+            skipped_breakout,
+            channel_exit_price,
+            stop_price
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &TurtleResult) -> Option<Signal> {
+        let entry_price = indicator.entry_price?;
+        let strength = scaled_strength(stock_price_info.close - indicator.entry_channel_high, indicator.n);
+        Some(Signal::new(entry_price, strength, "donchian_breakout"))
+    }
+
+    fn sell_signal(&self, _: &StockPriceInfo, indicator: &TurtleResult) -> Option<Signal> {
+        match (indicator.stop_price, indicator.channel_exit_price) {
+            (Some(stop_price), _) => Some(Signal::full_strength(stop_price, "turtle_2n_stop")),
+            (None, Some(exit_price)) => Some(Signal::full_strength(exit_price, "donchian_exit")),
+            (None, None) => None
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = TurtleStrategy::new(self.system)
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::relative_volume::RelativeVolume;
+
+/// Wraps any strategy and lets its buy signals through only on bars whose volume is above
+/// `min_relative_volume` times the average of the previous `volume_length` bars. Sell signals
+/// are never filtered.
+pub struct VolumeConfirmation<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    strategy: S,
+    relative_volume: RelativeVolume,
+    min_relative_volume: f32,
+    _phantom: PhantomData<T>
+}
+
+#[derive(Clone)]
+pub struct VolumeConfirmationResult<T> {
+    pub strategy_result: T,
+    pub relative_volume: f32,
+    pub is_ready: bool
+}
+
+impl<T, S> VolumeConfirmation<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    pub fn new(strategy: S, volume_length: usize, min_relative_volume: f32) -> Self {
+        Self {
+            strategy,
+            relative_volume: RelativeVolume::new(volume_length),
+            min_relative_volume,
+            _phantom: PhantomData
+        }
+    }
+
+    /// Buys only on volume above the `volume_length`-day average.
+    pub fn above_average(strategy: S, volume_length: usize) -> Self {
+        Self::new(strategy, volume_length, 1.0)
+    }
+}
+
+impl<T, S> InvestingStrategy<VolumeConfirmationResult<T>> for VolumeConfirmation<T, S>
+where
+    S: InvestingStrategy<T>
+{
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, context: &StrategyContext) -> VolumeConfirmationResult<T> {
+        VolumeConfirmationResult {
+            strategy_result: self.strategy.calculation(stock_price_info, yesterday, context),
+            relative_volume: self.relative_volume.next(stock_price_info.vol),
+            is_ready: self.relative_volume.is_ready()
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &VolumeConfirmationResult<T>) -> Option<Signal> {
+        if indicator.is_ready && indicator.relative_volume > self.min_relative_volume {
+            self.strategy.buy_signal(stock_price_info, &indicator.strategy_result)
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &VolumeConfirmationResult<T>) -> Option<Signal> {
+        self.strategy.sell_signal(stock_price_info, &indicator.strategy_result)
+    }
+
+    fn reset(&mut self) {
+        self.strategy.reset();
+        self.relative_volume.reset();
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(Self {
+            strategy: self.strategy.fork()?,
+            relative_volume: self.relative_volume.clone(),
+            min_relative_volume: self.min_relative_volume,
+            _phantom: PhantomData
+        })
+    }
+}
@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Datelike;
+use wasmi::{Config, Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder, TypedFunc};
+use crate::signal::Signal;
+use crate::StockPriceInfo;
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+
+pub const WASM_PLUGIN_ABI_VERSION: i32 = 1;
+
+type OnBar = TypedFunc<(i32, f32, f32, f32, f32, f32), ()>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct WasmPluginLimits {
+    /// Fuel available to a single `on_bar` call (and to instantiation), roughly one unit per instruction.
+    pub fuel_per_bar: u64,
+    pub max_memory_bytes: usize
+}
+
+impl Default for WasmPluginLimits {
+    fn default() -> Self {
+        Self {
+            fuel_per_bar: 1_000_000,
+            max_memory_bytes: 16 * 1024 * 1024
+        }
+    }
+}
+
+/// Strategy compiled to WASM and loaded at runtime. A plugin imports nothing and exports:
+///
+/// - `abi_version() -> i32` returning [`WASM_PLUGIN_ABI_VERSION`],
+/// - `on_bar(date: i32, open: f32, high: f32, low: f32, close: f32, volume: f32)` called once per bar,
+///   with the date encoded as yyyymmdd,
+/// - `buy_signal() -> f32` and `sell_signal() -> f32` read after every `on_bar`, returning the
+///   signal strength in (0, 1] or zero when there is no signal.
+///
+/// Signals are executed at the bar close. The module is compiled once and instantiated per ticker,
+/// each instance in its own store with fuel and memory limits.
+#[derive(Clone)]
+pub struct WasmPlugin {
+    name: String,
+    engine: Engine,
+    module: Arc<Module>,
+    limits: WasmPluginLimits
+}
+
+impl WasmPlugin {
+    pub fn from_file(path: &Path, limits: WasmPluginLimits) -> Result<Self, Box<dyn Error>> {
+        let name = path.file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "wasm_plugin".to_string());
+        Self::new(&name, &fs::read(path)?, limits)
+    }
+
+    pub fn new(name: &str, wasm: &[u8], limits: WasmPluginLimits) -> Result<Self, Box<dyn Error>> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Arc::new(Module::new(&engine, wasm)?);
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            module,
+            limits
+        })
+    }
+
+    pub fn instantiate(&self) -> Result<WasmPluginStrategy, Box<dyn Error>> {
+        let store_limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.max_memory_bytes)
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, store_limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.limits.fuel_per_bar).map_err(|error| error.to_string())?;
+
+        let instance = Linker::<StoreLimits>::new(&self.engine)
+            .instantiate(&mut store, &self.module)?
+            .start(&mut store)?;
+        let abi_version = instance.get_typed_func::<(), i32>(&store, "abi_version")?.call(&mut store, ())?;
+        if abi_version != WASM_PLUGIN_ABI_VERSION {
+            return Err(format!("plugin {} uses ABI version {}, expected {}", self.name, abi_version, WASM_PLUGIN_ABI_VERSION).into());
+        }
+
+        Ok(WasmPluginStrategy {
+            on_bar: instance.get_typed_func(&store, "on_bar")?,
+            buy_signal: instance.get_typed_func(&store, "buy_signal")?,
+            sell_signal: instance.get_typed_func(&store, "sell_signal")?,
+            store,
+            plugin: self.clone(),
+            reason: format!("wasm_plugin_{}", self.name),
+            fuel_per_bar: self.limits.fuel_per_bar,
+            failure: None
+        })
+    }
+}
+
+pub struct WasmPluginStrategy {
+    store: Store<StoreLimits>,
+    on_bar: OnBar,
+    buy_signal: TypedFunc<(), f32>,
+    sell_signal: TypedFunc<(), f32>,
+    plugin: WasmPlugin,
+    reason: String,
+    fuel_per_bar: u64,
+    failure: Option<String>
+}
+
+#[derive(Clone)]
+pub struct WasmPluginResult {
+    pub buy_strength: Option<f32>,
+    pub sell_strength: Option<f32>
+}
+
+impl WasmPluginStrategy {
+    /// The trap that stopped the plugin, e.g. running out of fuel or memory. A failed plugin
+    /// produces no further signals.
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+
+    fn run_bar(&mut self, stock_price_info: &StockPriceInfo) -> Result<WasmPluginResult, Box<dyn Error>> {
+        self.store.set_fuel(self.fuel_per_bar).map_err(|error| error.to_string())?;
+        let date = stock_price_info.date;
+        let encoded_date = date.year() * 10000 + date.month() as i32 * 100 + date.day() as i32;
+        self.on_bar.call(&mut self.store, (
+            encoded_date,
+            stock_price_info.open,
+            stock_price_info.high,
+            stock_price_info.low,
+            stock_price_info.close,
+            stock_price_info.vol
+        ))?;
+        let buy_strength = self.buy_signal.call(&mut self.store, ())?;
+        let sell_strength = self.sell_signal.call(&mut self.store, ())?;
+        Ok(WasmPluginResult {
+            buy_strength: Some(buy_strength).filter(|strength| *strength > 0.0),
+            sell_strength: Some(sell_strength).filter(|strength| *strength > 0.0)
+        })
+    }
+}
+
+impl InvestingStrategy<WasmPluginResult> for WasmPluginStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> WasmPluginResult {
+        let no_signals = WasmPluginResult {
+            buy_strength: None,
+            sell_strength: None
+        };
+        if self.failure.is_some() {
+            return no_signals;
+        }
+        match self.run_bar(stock_price_info) {
+            Ok(result) => result,
+            Err(error) => {
+                self.failure = Some(format!("{}: {}", stock_price_info.date, error));
+                no_signals
+            }
+        }
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &WasmPluginResult) -> Option<Signal> {
+        indicator.buy_strength.map(|strength| Signal::new(stock_price_info.close, strength, &self.reason))
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &WasmPluginResult) -> Option<Signal> {
+        indicator.sell_strength.map(|strength| Signal::new(stock_price_info.close, strength, &self.reason))
+    }
+
+    /// Starts a fresh instance of the plugin, which also clears a previous failure.
+    fn reset(&mut self) {
+        match self.plugin.instantiate() {
+            Ok(instance) => *self = instance,
+            Err(error) => self.failure = Some(format!("reset: {}", error))
+        }
+    }
+
+    /// The instance state lives in the WASM store, which cannot be copied.
+    fn fork(&self) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use super::*;
+
+    const PAGE_BYTES: usize = 64 * 1024;
+
+    fn plugin(on_bar: &str, extra: &str, limits: WasmPluginLimits) -> Result<WasmPlugin, Box<dyn Error>> {
+        let wat = format!(r#"(module
+            {extra}
+            (func (export "abi_version") (result i32) i32.const 1)
+            (func (export "on_bar") (param i32 f32 f32 f32 f32 f32) {on_bar})
+            (func (export "buy_signal") (result f32) f32.const 1)
+            (func (export "sell_signal") (result f32) f32.const 0))"#);
+        WasmPlugin::new("test", &wat::parse_str(wat)?, limits)
+    }
+
+    fn bar() -> StockPriceInfo {
+        StockPriceInfo {
+            ticker: "AAPL.US".to_string(),
+            per: "D".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            time: "000000".to_string(),
+            open: 10.0,
+            high: 10.0,
+            low: 10.0,
+            close: 10.0,
+            vol: 1000.0,
+            openint: 0
+        }
+    }
+
+    #[test]
+    fn endless_on_bar_runs_out_of_fuel_and_stops_the_plugin() {
+        let limits = WasmPluginLimits { fuel_per_bar: 10_000, ..WasmPluginLimits::default() };
+        let mut strategy = plugin("(loop $forever br $forever)", "", limits).unwrap().instantiate().unwrap();
+
+        let result = strategy.calculation(&bar(), &None, &StrategyContext::default());
+
+        assert!(result.buy_strength.is_none() && result.sell_strength.is_none());
+        assert!(strategy.failure().is_some_and(|failure| failure.starts_with("2024-01-02") && failure.contains("fuel")));
+        let result = strategy.calculation(&bar(), &None, &StrategyContext::default());
+        assert!(result.buy_strength.is_none());
+    }
+
+    #[test]
+    fn memory_growth_past_the_limit_is_refused() {
+        // Traps unless growing the memory by 16 pages (1 MiB) succeeds.
+        let grow = "(if (i32.eq (memory.grow (i32.const 16)) (i32.const -1)) (then unreachable))";
+        let limits = |max_memory_bytes| WasmPluginLimits { max_memory_bytes, ..WasmPluginLimits::default() };
+
+        let mut within_limit = plugin(grow, "(memory 1)", limits(32 * PAGE_BYTES)).unwrap().instantiate().unwrap();
+        let result = within_limit.calculation(&bar(), &None, &StrategyContext::default());
+        assert_eq!(result.buy_strength, Some(1.0));
+        assert!(within_limit.failure().is_none());
+
+        let mut past_limit = plugin(grow, "(memory 1)", limits(8 * PAGE_BYTES)).unwrap().instantiate().unwrap();
+        let result = past_limit.calculation(&bar(), &None, &StrategyContext::default());
+        assert!(result.buy_strength.is_none());
+        assert!(past_limit.failure().is_some());
+
+        // A module asking for more initial memory than allowed isn't instantiated at all.
+        assert!(plugin("", "(memory 16)", limits(8 * PAGE_BYTES)).unwrap().instantiate().is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use crate::StockPriceInfo;
+use crate::signal::{scaled_strength, Signal};
+use crate::strategy_simulator::{InvestingStrategy, StrategyContext};
+use crate::technical_indicator::williams_r::WilliamsR;
+
+/// Bands are on the indicator's own -100..0 scale, usually -80 and -20.
+#[derive(Clone)]
+pub struct WilliamsRStrategy {
+    williams_r: WilliamsR,
+    lower_band: f32,
+    higher_band: f32
+}
+
+impl WilliamsRStrategy {
+    pub fn new(length: usize, lower_band: f32, higher_band: f32) -> Self {
+        WilliamsRStrategy {
+            williams_r: WilliamsR::new(length),
+            lower_band,
+            higher_band
+        }
+    }
+}
+
+impl InvestingStrategy<f32> for WilliamsRStrategy {
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, _: &Option<StockPriceInfo>, _: &StrategyContext) -> f32 {
+        self.williams_r.next(stock_price_info.high, stock_price_info.low, stock_price_info.close)
+    }
+
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.williams_r.is_ready() && *indicator < self.lower_band {
+            let strength = scaled_strength(self.lower_band - *indicator, 100.0 + self.lower_band);
+            Some(Signal::new(stock_price_info.close, strength, "williams_r_oversold"))
+        } else {
+            None
+        }
+    }
+
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &f32) -> Option<Signal> {
+        if self.williams_r.is_ready() && *indicator > self.higher_band {
+            let strength = scaled_strength(*indicator - self.higher_band, -self.higher_band);
+            Some(Signal::new(stock_price_info.close, strength, "williams_r_overbought"))
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.williams_r.reset()
+    }
+
+    fn fork(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
@@ -1,31 +1,78 @@
 use std::cmp::max;
+use std::sync::Arc;
 use chrono::NaiveDate;
 use crate::broker_fee::BrokerFee;
+use crate::signal::Signal;
 use crate::StockPriceInfo;
+use crate::stock_data_reader::benchmark::Benchmark;
+use crate::stock_data_reader::resampler::{Resampler, Timeframe};
 use crate::stop_loss_strategy::StopLossTrigger;
 use crate::strategy_simulator::TradeResult::{Buy, Sell, StopLoss, TakeProfit};
 use crate::take_profit_strategy::TakeProfitTrigger;
 
 pub trait InvestingStrategy<T> {
-    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> T;
-    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &T) -> Option<f32>;
-    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &T) -> Option<f32>;
+    fn calculation(&mut self, stock_price_info: &StockPriceInfo, yesterday: &Option<StockPriceInfo>, context: &StrategyContext) -> T;
+    fn buy_signal(&self, stock_price_info: &StockPriceInfo, indicator: &T) -> Option<Signal>;
+    fn sell_signal(&self, stock_price_info: &StockPriceInfo, indicator: &T) -> Option<Signal>;
+    /// Brings the strategy back to the state it had right after construction.
+    fn reset(&mut self);
+    /// Copy of the strategy including its indicator state, or `None` when part of the state lives
+    /// outside of this process and cannot be duplicated.
+    fn fork(&self) -> Option<Self> where Self: Sized;
+}
+
+/// Object safe counterpart of `InvestingStrategy::fork`, implemented for every strategy so the
+/// simulator can fork the strategy it holds in a `Box`.
+pub trait ForkableStrategy<T>: InvestingStrategy<T> {
+    fn fork_boxed(&self) -> Option<Box<dyn ForkableStrategy<T>>>;
+}
+
+impl<T: 'static, S: InvestingStrategy<T> + 'static> ForkableStrategy<T> for S {
+    fn fork_boxed(&self) -> Option<Box<dyn ForkableStrategy<T>>> {
+        self.fork().map(|strategy| Box::new(strategy) as Box<dyn ForkableStrategy<T>>)
+    }
+}
+
+/// Data from outside of the daily bar being simulated.
+#[derive(Clone, Default)]
+pub struct StrategyContext {
+    /// Weekly bar that completed before today, present only on the first day it becomes visible.
+    pub weekly: Option<StockPriceInfo>,
+    /// Monthly bar that completed before today, present only on the first day it becomes visible.
+    pub monthly: Option<StockPriceInfo>,
+    /// Benchmark bar of the same date, if the simulator has a benchmark and it traded that day.
+    pub benchmark: Option<StockPriceInfo>
+}
+
+impl StrategyContext {
+    pub fn completed_bar(&self, timeframe: Timeframe) -> Option<&StockPriceInfo> {
+        match timeframe {
+            Timeframe::Weekly => self.weekly.as_ref(),
+            Timeframe::Monthly => self.monthly.as_ref()
+        }
+    }
 }
 
 pub struct StrategySimulator<T> {
-    strategy: Box<dyn InvestingStrategy<T>>,
+    strategy: Box<dyn ForkableStrategy<T>>,
     take_profit: Box<dyn TakeProfitTrigger>,
     stop_loss: Box<dyn StopLossTrigger>,
     broker_fee: Box<dyn BrokerFee>,
+    invested_cash: f32,
     cash: f32,
     start_date: NaiveDate,
     last_buy_price: f32,
     current_position: usize,
+    weekly_resampler: Resampler,
+    monthly_resampler: Resampler,
+    benchmark: Option<Arc<Benchmark>>,
 }
 
 pub struct Trade {
     pub price: f32,
-    pub after_operation_cash: f32
+    pub after_operation_cash: f32,
+    pub strength: f32,
+    pub reason: String
 }
 
 pub struct StrategyResult<T> {
@@ -43,7 +90,7 @@ pub enum TradeResult {
 impl<T: Clone> StrategySimulator<T>  {
     pub fn new(invested_cash: f32,
                start_date: NaiveDate,
-               strategy: Box<dyn InvestingStrategy<T>>,
+               strategy: Box<dyn ForkableStrategy<T>>,
                take_profit: Box<dyn TakeProfitTrigger>,
                stop_loss: Box<dyn StopLossTrigger>,
                broker_fee: Box<dyn BrokerFee>) -> Self<> {
@@ -52,35 +99,98 @@ impl<T: Clone> StrategySimulator<T>  {
             take_profit,
             stop_loss,
             broker_fee,
+            invested_cash,
             cash: invested_cash,
             start_date: start_date,
             last_buy_price: 0.0f32,
             current_position: 0,
+            weekly_resampler: Resampler::new(Timeframe::Weekly),
+            monthly_resampler: Resampler::new(Timeframe::Monthly),
+            benchmark: None,
         }
     }
 
+    pub fn with_benchmark(mut self, benchmark: Arc<Benchmark>) -> Self {
+        self.benchmark = Some(benchmark);
+        self
+    }
+
+    /// Replaces the stop loss, e.g. to try a different stop on a fork from the fork date on.
+    pub fn with_stop_loss(mut self, stop_loss: Box<dyn StopLossTrigger>) -> Self {
+        self.stop_loss = stop_loss;
+        self
+    }
+
+    pub fn with_take_profit(mut self, take_profit: Box<dyn TakeProfitTrigger>) -> Self {
+        self.take_profit = take_profit;
+        self
+    }
+
+    /// Copy of the simulator with the cash, open position and strategy state it has after the last
+    /// processed bar. Feeding both with the remaining bars runs two independent what-if branches
+    /// without replaying the history before the fork. `None` when the strategy cannot be forked.
+    pub fn fork(&self) -> Option<StrategySimulator<T>> {
+        Some(Self {
+            strategy: self.strategy.fork_boxed()?,
+            take_profit: self.take_profit.fork(),
+            stop_loss: self.stop_loss.fork(),
+            broker_fee: self.broker_fee.fork(),
+            invested_cash: self.invested_cash,
+            cash: self.cash,
+            start_date: self.start_date,
+            last_buy_price: self.last_buy_price,
+            current_position: self.current_position,
+            weekly_resampler: self.weekly_resampler.clone(),
+            monthly_resampler: self.monthly_resampler.clone(),
+            benchmark: self.benchmark.clone(),
+        })
+    }
+
+    /// Starts over with the invested cash and a fresh strategy, so one simulator can be reused
+    /// across configurations and tickers.
+    pub fn reset(&mut self) {
+        self.strategy.reset();
+        self.take_profit.reset();
+        self.stop_loss.reset();
+        self.broker_fee.reset();
+        self.cash = self.invested_cash;
+        self.last_buy_price = 0.0f32;
+        self.current_position = 0;
+        self.weekly_resampler.reset();
+        self.monthly_resampler.reset();
+    }
+
     pub fn next_today(&mut self, today: &StockPriceInfo) -> StrategyResult<T> {
         self.next(today, &None)
     }
 
     pub fn next(&mut self, today: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> StrategyResult<T> {
-        let metric_result = self.strategy.calculation(&today, yesterday);
+        let context = StrategyContext {
+            weekly: self.weekly_resampler.next(today),
+            monthly: self.monthly_resampler.next(today),
+            benchmark: self.benchmark.as_ref().and_then(|benchmark| benchmark.get(today.date).cloned())
+        };
+        let metric_result = self.strategy.calculation(&today, yesterday, &context);
         let mut operations_performed = vec![];
         if today.date >= self.start_date {
             if self.current_position > 0 {
-                if let Some(sell_price) = self.strategy.sell_signal(&today, &metric_result) {
-                    self.sell_operation(sell_price);
-                    //println!("{}: Selling at {}, cash: {}", today.date, sell_price, self.cash);
+                if let Some(sell_signal) = self.strategy.sell_signal(&today, &metric_result) {
+                    self.sell_operation(sell_signal.price);
+                    //println!("{}: Selling at {}, cash: {}", today.date, sell_signal.price, self.cash);
                     operations_performed.push(Sell(Trade {
-                        price: sell_price,
-                        after_operation_cash: self.cash
+                        price: sell_signal.price,
+                        after_operation_cash: self.cash,
+                        strength: sell_signal.strength,
+                        reason: sell_signal.reason
                     }));
                 }
                 if let Some(take_profit_price) = self.take_profit.should_trigger_take_profit(today, self.last_buy_price) {
                     self.sell_operation(take_profit_price);
                     operations_performed.push(TakeProfit(Trade {
                         price: take_profit_price,
-                        after_operation_cash: self.cash
+                        after_operation_cash: self.cash,
+                        strength: 1.0,
+                        reason: "take_profit".to_string()
                     }))
                 }
                 if let Some(stop_loss_price) = self.stop_loss.should_trigger_stop_loss(today, self.last_buy_price) {
@@ -89,16 +199,20 @@ impl<T: Clone> StrategySimulator<T>  {
                     operations_performed.push(StopLoss(Trade {
                         price: stop_loss_price,
                         after_operation_cash: self.cash,
+                        strength: 1.0,
+                        reason: "stop_loss".to_string()
                     }))
                 }
             }
             if self.current_position == 0 {
-                if let Some(buy_price) = self.strategy.buy_signal(&today, &metric_result) {
-                    self.buy_operation(buy_price);
-                    //println!("{}: Buying at {} number of shares: {}, cash left: {}", today.date, buy_price, self.current_position, self.cash);
+                if let Some(buy_signal) = self.strategy.buy_signal(&today, &metric_result) {
+                    self.buy_operation(buy_signal.price);
+                    //println!("{}: Buying at {} number of shares: {}, cash left: {}", today.date, buy_signal.price, self.current_position, self.cash);
                     operations_performed.push(Buy(Trade {
-                        price: buy_price,
+                        price: buy_signal.price,
                         after_operation_cash: self.cash,
+                        strength: buy_signal.strength,
+                        reason: buy_signal.reason
                     }))
                 }
             }
@@ -110,6 +224,19 @@ impl<T: Clone> StrategySimulator<T>  {
         }
     }
 
+    /// Cash plus the open position marked to `price`.
+    pub fn equity(&self, price: f32) -> f32 {
+        self.cash + self.current_position as f32 * price
+    }
+
+    /// Cash left after selling the open position at `price`, net of the sell fee, which makes it
+    /// comparable with the baselines that sell their shares at the end as well.
+    pub fn liquidation_value(&self, price: f32) -> f32 {
+        self.cash +
+            price * self.current_position as f32 -
+            self.broker_fee.sell_fee(self.current_position, price)
+    }
+
     fn sell_operation(&mut self, sell_price: f32) {
         self.cash = self.cash +
             sell_price * self.current_position as f32 -
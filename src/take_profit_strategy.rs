@@ -2,8 +2,12 @@ use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
 
 pub trait TakeProfitTrigger {
     fn should_trigger_take_profit(&self, stock_price_info: &StockPriceInfo, last_buy_price: f32) -> Option<f32>;
+    /// Stateless take profits have nothing to reset.
+    fn reset(&mut self) {}
+    fn fork(&self) -> Box<dyn TakeProfitTrigger>;
 }
 
+#[derive(Clone)]
 pub struct PercentageTakeProfit {
     take_profit_percentage: f32
 }
@@ -24,12 +28,21 @@ impl TakeProfitTrigger for PercentageTakeProfit {
             None
         }
     }
+
+    fn fork(&self) -> Box<dyn TakeProfitTrigger> {
+        Box::new(self.clone())
+    }
 }
 
+#[derive(Clone)]
 pub struct NoTakeProfit;
 
 impl TakeProfitTrigger for NoTakeProfit {
     fn should_trigger_take_profit(&self, _: &StockPriceInfo, _: f32) -> Option<f32> {
         None
     }
+
+    fn fork(&self) -> Box<dyn TakeProfitTrigger> {
+        Box::new(self.clone())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+
+/// Volume weighted by where the close sits within the bar's range: all of it counts as buying
+/// at the high, as selling at the low, and none of it in the middle.
+pub fn money_flow_volume(high: f32, low: f32, close: f32, volume: f32) -> f32 {
+    let range = high - low;
+    if range > 0.0 {
+        ((close - low) - (high - close)) / range * volume
+    } else {
+        0.0
+    }
+}
+
+/// Accumulation/Distribution line: running total of the money flow volume.
+#[derive(Clone, Default)]
+pub struct AccumulationDistribution {
+    line: f32,
+    samples: usize
+}
+
+impl AccumulationDistribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32, volume: f32) -> f32 {
+        self.line += money_flow_volume(high, low, close, volume);
+        self.samples += 1;
+        self.line
+    }
+
+    pub fn current(&self) -> f32 {
+        self.line
+    }
+
+    pub fn reset(&mut self) {
+        self.line = 0.0;
+        self.samples = 0;
+    }
+}
+
+impl Indicator for AccumulationDistribution {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        AccumulationDistribution::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.samples > 0
+    }
+
+    fn reset(&mut self) {
+        AccumulationDistribution::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+}
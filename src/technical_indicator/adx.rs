@@ -0,0 +1,119 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::atr::true_range;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::technical_indicator::wilder_average::WilderAverage;
+
+/// Wilder's directional movement system: +DI and −DI compare the up and down moves of the
+/// highs and lows with the true range, and ADX, the smoothed spread between them, measures how
+/// strongly the market trends regardless of direction.
+#[derive(Clone)]
+pub struct Adx {
+    true_range: WilderAverage,
+    plus_directional_movement: WilderAverage,
+    minus_directional_movement: WilderAverage,
+    adx: WilderAverage,
+    previous_bar: Option<(f32, f32, f32)>
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AdxResult {
+    pub adx: f32,
+    pub plus_di: f32,
+    pub minus_di: f32
+}
+
+impl Adx {
+    pub fn new(length: usize) -> Self {
+        Self {
+            true_range: WilderAverage::new(length),
+            plus_directional_movement: WilderAverage::new(length),
+            minus_directional_movement: WilderAverage::new(length),
+            adx: WilderAverage::new(length),
+            previous_bar: None
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> AdxResult {
+        if let Some((previous_high, previous_low, previous_close)) = self.previous_bar {
+            let up_move = high - previous_high;
+            let down_move = previous_low - low;
+            let plus_dm = if up_move > down_move && up_move > 0.0 { up_move } else { 0.0 };
+            let minus_dm = if down_move > up_move && down_move > 0.0 { down_move } else { 0.0 };
+            let true_range = true_range(high, low, previous_close);
+
+            self.true_range.next(true_range);
+            self.plus_directional_movement.next(plus_dm);
+            self.minus_directional_movement.next(minus_dm);
+            if self.true_range.is_ready() {
+                let (plus_di, minus_di) = self.directional_indicators();
+                let di_sum = plus_di + minus_di;
+                let dx = if di_sum > 0.0 { 100.0 * (plus_di - minus_di).abs() / di_sum } else { 0.0 };
+                self.adx.next(dx);
+            }
+        }
+        self.previous_bar = Some((high, low, close));
+        self.current()
+    }
+
+    fn directional_indicators(&self) -> (f32, f32) {
+        let true_range = self.true_range.current();
+        if true_range > 0.0 {
+            (100.0 * self.plus_directional_movement.current() / true_range,
+             100.0 * self.minus_directional_movement.current() / true_range)
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn current(&self) -> AdxResult {
+        let (plus_di, minus_di) = self.directional_indicators();
+        AdxResult {
+            adx: self.adx.current(),
+            plus_di,
+            minus_di
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.adx.is_ready()
+    }
+
+    pub fn reset(&mut self) {
+        self.true_range.reset();
+        self.plus_directional_movement.reset();
+        self.minus_directional_movement.reset();
+        self.adx.reset();
+        self.previous_bar = None;
+    }
+}
+
+impl IndicatorOutput for AdxResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.adx, self.plus_di, self.minus_di]
+    }
+}
+
+impl Indicator for Adx {
+    type Output = AdxResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> AdxResult {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> AdxResult {
+        Adx::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Adx::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Adx::reset(self)
+    }
+
+    /// One bar for the first move, `length` moves for the DIs and `length` DX values for ADX.
+    fn lookback(&self) -> usize {
+        2 * self.adx.length()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Aroon up/down: how recently, within the last `length` bars, the highest high and the lowest
+/// low were made, from 100 for today to 0 for `length` bars ago.
+#[derive(Clone)]
+pub struct Aroon {
+    length: usize,
+    bars: RollingWindow<(f32, f32)>
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AroonResult {
+    pub up: f32,
+    pub down: f32
+}
+
+impl AroonResult {
+    pub fn oscillator(&self) -> f32 {
+        self.up - self.down
+    }
+}
+
+impl Aroon {
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            bars: RollingWindow::new(length + 1)
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32) -> AroonResult {
+        self.bars.add((high, low));
+        self.current()
+    }
+
+    pub fn current(&self) -> AroonResult {
+        let newest = self.bars.len().saturating_sub(1);
+        // Ties go to the most recent bar.
+        let (highest_index, _) = self.bars.iter().enumerate()
+            .fold((0, f32::NEG_INFINITY), |best, (index, &(high, _))| if high >= best.1 { (index, high) } else { best });
+        let (lowest_index, _) = self.bars.iter().enumerate()
+            .fold((0, f32::INFINITY), |best, (index, &(_, low))| if low <= best.1 { (index, low) } else { best });
+        let to_aroon = |index: usize| 100.0 * (self.length as f32 - (newest - index) as f32) / self.length as f32;
+        AroonResult {
+            up: to_aroon(highest_index),
+            down: to_aroon(lowest_index)
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.bars.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.bars.clear();
+    }
+}
+
+impl IndicatorOutput for AroonResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.up, self.down, self.oscillator()]
+    }
+}
+
+impl Indicator for Aroon {
+    type Output = AroonResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> AroonResult {
+        self.next(bar.high, bar.low)
+    }
+
+    fn current(&self) -> AroonResult {
+        Aroon::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Aroon::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Aroon::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.bars.capacity()
+    }
+}
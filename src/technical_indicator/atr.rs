@@ -1,23 +1,70 @@
-use crate::technical_indicator::ema::Ema;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::ema::EmaSeed;
+use crate::technical_indicator::indicator::Indicator;
+use crate::technical_indicator::smoothing::{MovingAverage, Smoothing};
 
+/// Average true range, the true range being the bar's range extended to the previous close when
+/// the bar gapped away from it. Wilder's smoothing for the standard ATR.
+#[derive(Clone)]
 pub struct Atr {
-    atr_ema: Ema
+    average_true_range: MovingAverage,
+    previous_close: Option<f32>
 }
 
 impl Atr {
     pub fn new(length: usize) -> Self {
-        Self { atr_ema: Ema::new(length)  }
+        Self::with_smoothing(length, Smoothing::Wilder)
+    }
+
+    pub fn with_smoothing(length: usize, smoothing: Smoothing) -> Self {
+        Self { average_true_range: MovingAverage::new(smoothing, length), previous_close: None }
+    }
+
+    pub fn with_smoothing_and_seed(length: usize, smoothing: Smoothing, seed: EmaSeed) -> Self {
+        Self { average_true_range: MovingAverage::with_seed(smoothing, length, seed), previous_close: None }
     }
 
     pub fn next(&mut self, today_high: f32, today_low: f32, yesterday_close: f32) -> f32 {
-        let one = today_high - today_high;
-        let two = today_high - yesterday_close;
-        let three = yesterday_close - today_low;
-        let true_range = one.max(two).max(three);
-        self.atr_ema.next(true_range)
+        self.average_true_range.next(true_range(today_high, today_low, yesterday_close))
     }
 
     pub fn current(&self) -> f32 {
-        return self.atr_ema.current()
+        self.average_true_range.current()
+    }
+
+    pub fn reset(&mut self) {
+        self.average_true_range.reset();
+        self.previous_close = None;
     }
-}
\ No newline at end of file
+}
+
+pub fn true_range(today_high: f32, today_low: f32, yesterday_close: f32) -> f32 {
+    f32::max(today_high, yesterday_close) - f32::min(today_low, yesterday_close)
+}
+
+impl Indicator for Atr {
+    type Output = f32;
+
+    /// The very first bar has no previous close and contributes its high - low range.
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        let previous_close = self.previous_close.unwrap_or(bar.close);
+        self.previous_close = Some(bar.close);
+        self.next(bar.high, bar.low, previous_close)
+    }
+
+    fn current(&self) -> f32 {
+        Atr::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.average_true_range.is_ready()
+    }
+
+    fn reset(&mut self) {
+        Atr::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.average_true_range.seed_period()
+    }
+}
@@ -0,0 +1,113 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput, ValueIndicator};
+use crate::technical_indicator::standard_deviation::StandardDeviation;
+
+/// `length` SMA with bands `band_width` standard deviations above and below it.
+#[derive(Clone)]
+pub struct BollingerBands {
+    band_width: f32,
+    standard_deviation: StandardDeviation,
+    last_price: f32
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BollingerBandsResult {
+    pub middle_band: f32,
+    pub upper_band: f32,
+    pub lower_band: f32,
+    /// Position of the price between the bands: 0 at the lower band, 1 at the upper one.
+    pub percent_b: f32,
+    /// Distance between the bands relative to the middle band.
+    pub bandwidth: f32
+}
+
+impl From<BollingerBandsResult> for Vec<f32> {
+    fn from(result: BollingerBandsResult) -> Self {
+        vec![result.lower_band, result.middle_band, result.upper_band, result.percent_b, result.bandwidth]
+    }
+}
+
+impl BollingerBands {
+    pub fn new(length: usize, band_width: f32) -> Self {
+        Self {
+            band_width,
+            standard_deviation: StandardDeviation::new(length),
+            last_price: 0.0
+        }
+    }
+
+    pub fn next(&mut self, price: f32) -> BollingerBandsResult {
+        self.standard_deviation.next(price);
+        self.last_price = price;
+        self.current()
+    }
+
+    pub fn current(&self) -> BollingerBandsResult {
+        let middle_band = self.standard_deviation.mean();
+        let offset = self.band_width * self.standard_deviation.current();
+        let upper_band = middle_band + offset;
+        let lower_band = middle_band - offset;
+        let percent_b = if upper_band > lower_band {
+            (self.last_price - lower_band) / (upper_band - lower_band)
+        } else {
+            0.5
+        };
+        let bandwidth = if middle_band != 0.0 {
+            (upper_band - lower_band) / middle_band
+        } else {
+            0.0
+        };
+        BollingerBandsResult {
+            middle_band,
+            upper_band,
+            lower_band,
+            percent_b,
+            bandwidth
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.standard_deviation.is_ready()
+    }
+
+    pub fn reset(&mut self) {
+        self.standard_deviation.reset();
+        self.last_price = 0.0;
+    }
+}
+
+impl IndicatorOutput for BollingerBandsResult {
+    fn to_values(&self) -> Vec<f32> {
+        Vec::from(*self)
+    }
+}
+
+impl Indicator for BollingerBands {
+    type Output = BollingerBandsResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> BollingerBandsResult {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> BollingerBandsResult {
+        BollingerBands::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        BollingerBands::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        BollingerBands::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.standard_deviation.lookback()
+    }
+}
+
+impl ValueIndicator for BollingerBands {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
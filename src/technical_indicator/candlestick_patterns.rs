@@ -0,0 +1,246 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::utils::rolling_window::RollingWindow;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CandlestickPattern {
+    Doji,
+    Hammer,
+    InvertedHammer,
+    BullishEngulfing,
+    BearishEngulfing,
+    MorningStar,
+    EveningStar,
+    ThreeWhiteSoldiers,
+    InsideBar,
+    OutsideBar
+}
+
+impl CandlestickPattern {
+    pub const ALL: [CandlestickPattern; 10] = [
+        CandlestickPattern::Doji,
+        CandlestickPattern::Hammer,
+        CandlestickPattern::InvertedHammer,
+        CandlestickPattern::BullishEngulfing,
+        CandlestickPattern::BearishEngulfing,
+        CandlestickPattern::MorningStar,
+        CandlestickPattern::EveningStar,
+        CandlestickPattern::ThreeWhiteSoldiers,
+        CandlestickPattern::InsideBar,
+        CandlestickPattern::OutsideBar
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CandlestickPattern::Doji => "doji",
+            CandlestickPattern::Hammer => "hammer",
+            CandlestickPattern::InvertedHammer => "inverted_hammer",
+            CandlestickPattern::BullishEngulfing => "bullish_engulfing",
+            CandlestickPattern::BearishEngulfing => "bearish_engulfing",
+            CandlestickPattern::MorningStar => "morning_star",
+            CandlestickPattern::EveningStar => "evening_star",
+            CandlestickPattern::ThreeWhiteSoldiers => "three_white_soldiers",
+            CandlestickPattern::InsideBar => "inside_bar",
+            CandlestickPattern::OutsideBar => "outside_bar"
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Candle {
+    open: f32,
+    high: f32,
+    low: f32,
+    close: f32
+}
+
+impl Candle {
+    fn body(&self) -> f32 {
+        (self.close - self.open).abs()
+    }
+
+    fn range(&self) -> f32 {
+        self.high - self.low
+    }
+
+    fn upper_shadow(&self) -> f32 {
+        self.high - f32::max(self.open, self.close)
+    }
+
+    fn lower_shadow(&self) -> f32 {
+        f32::min(self.open, self.close) - self.low
+    }
+
+    fn is_bullish(&self) -> bool {
+        self.close > self.open
+    }
+
+    fn is_bearish(&self) -> bool {
+        self.close < self.open
+    }
+
+    fn body_midpoint(&self) -> f32 {
+        (self.open + self.close) / 2.0
+    }
+}
+
+#[derive(Clone)]
+pub struct CandlestickPatternsResult {
+    pub patterns: Vec<CandlestickPattern>
+}
+
+impl CandlestickPatternsResult {
+    pub fn contains(&self, pattern: CandlestickPattern) -> bool {
+        self.patterns.contains(&pattern)
+    }
+}
+
+/// One column per pattern in `CandlestickPattern::ALL` order, 1.0 when the pattern completed on the bar.
+impl From<CandlestickPatternsResult> for Vec<f32> {
+    fn from(result: CandlestickPatternsResult) -> Self {
+        CandlestickPattern::ALL.iter()
+            .map(|pattern| if result.contains(*pattern) { 1.0 } else { 0.0 })
+            .collect()
+    }
+}
+
+/// Recognises patterns completing on the latest bar. Patterns are defined by candle shape only,
+/// trend context is left to the strategies the patterns are combined with.
+#[derive(Clone)]
+pub struct CandlestickPatterns {
+    candles: RollingWindow<Candle>
+}
+
+impl CandlestickPatterns {
+    pub fn new() -> Self {
+        Self {
+            candles: RollingWindow::new(3)
+        }
+    }
+
+    pub fn next(&mut self, stock_price_info: &StockPriceInfo) -> CandlestickPatternsResult {
+        self.candles.add(Candle {
+            open: stock_price_info.open,
+            high: stock_price_info.high,
+            low: stock_price_info.low,
+            close: stock_price_info.close
+        });
+        self.patterns()
+    }
+
+    fn patterns(&self) -> CandlestickPatternsResult {
+        let candles: Vec<Candle> = self.candles.iter().cloned().collect();
+
+        let patterns = CandlestickPattern::ALL.iter()
+            .cloned()
+            .filter(|pattern| match (pattern, candles.as_slice()) {
+                (CandlestickPattern::Doji, [.., today]) => is_doji(today),
+                (CandlestickPattern::Hammer, [.., today]) => is_hammer(today),
+                (CandlestickPattern::InvertedHammer, [.., today]) => is_inverted_hammer(today),
+                (CandlestickPattern::BullishEngulfing, [.., yesterday, today]) => is_bullish_engulfing(yesterday, today),
+                (CandlestickPattern::BearishEngulfing, [.., yesterday, today]) => is_bearish_engulfing(yesterday, today),
+                (CandlestickPattern::MorningStar, [first, star, today]) => is_morning_star(first, star, today),
+                (CandlestickPattern::EveningStar, [first, star, today]) => is_evening_star(first, star, today),
+                (CandlestickPattern::ThreeWhiteSoldiers, [first, second, today]) => is_three_white_soldiers(first, second, today),
+                (CandlestickPattern::InsideBar, [.., yesterday, today]) => today.high < yesterday.high && today.low > yesterday.low,
+                (CandlestickPattern::OutsideBar, [.., yesterday, today]) => today.high > yesterday.high && today.low < yesterday.low,
+                _ => false
+            })
+            .collect();
+
+        CandlestickPatternsResult { patterns }
+    }
+
+    pub fn reset(&mut self) {
+        self.candles.clear()
+    }
+}
+
+impl IndicatorOutput for CandlestickPatternsResult {
+    fn to_values(&self) -> Vec<f32> {
+        self.clone().into()
+    }
+}
+
+impl Indicator for CandlestickPatterns {
+    type Output = CandlestickPatternsResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> CandlestickPatternsResult {
+        self.next(bar)
+    }
+
+    fn current(&self) -> CandlestickPatternsResult {
+        self.patterns()
+    }
+
+    /// Single candle patterns are recognised from the first bar, three candle ones need three.
+    fn is_ready(&self) -> bool {
+        self.candles.is_full()
+    }
+
+    fn reset(&mut self) {
+        CandlestickPatterns::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.candles.capacity()
+    }
+}
+
+fn is_doji(candle: &Candle) -> bool {
+    candle.range() > 0.0 && candle.body() <= 0.1 * candle.range()
+}
+
+fn is_hammer(candle: &Candle) -> bool {
+    candle.range() > 0.0 &&
+        candle.lower_shadow() >= 2.0 * candle.body() &&
+        candle.upper_shadow() <= 0.1 * candle.range() &&
+        !is_doji(candle)
+}
+
+fn is_inverted_hammer(candle: &Candle) -> bool {
+    candle.range() > 0.0 &&
+        candle.upper_shadow() >= 2.0 * candle.body() &&
+        candle.lower_shadow() <= 0.1 * candle.range() &&
+        !is_doji(candle)
+}
+
+fn is_bullish_engulfing(yesterday: &Candle, today: &Candle) -> bool {
+    yesterday.is_bearish() && today.is_bullish() &&
+        today.open <= yesterday.close && today.close >= yesterday.open &&
+        today.body() > yesterday.body()
+}
+
+fn is_bearish_engulfing(yesterday: &Candle, today: &Candle) -> bool {
+    yesterday.is_bullish() && today.is_bearish() &&
+        today.open >= yesterday.close && today.close <= yesterday.open &&
+        today.body() > yesterday.body()
+}
+
+fn is_long_body(candle: &Candle) -> bool {
+    candle.range() > 0.0 && candle.body() >= 0.5 * candle.range()
+}
+
+fn is_morning_star(first: &Candle, star: &Candle, today: &Candle) -> bool {
+    first.is_bearish() && is_long_body(first) &&
+        star.body() <= 0.3 * first.body() &&
+        f32::max(star.open, star.close) <= first.close &&
+        today.is_bullish() && today.close > first.body_midpoint()
+}
+
+fn is_evening_star(first: &Candle, star: &Candle, today: &Candle) -> bool {
+    first.is_bullish() && is_long_body(first) &&
+        star.body() <= 0.3 * first.body() &&
+        f32::min(star.open, star.close) >= first.close &&
+        today.is_bearish() && today.close < first.body_midpoint()
+}
+
+fn is_three_white_soldiers(first: &Candle, second: &Candle, today: &Candle) -> bool {
+    let soldier = |previous: &Candle, candle: &Candle| {
+        candle.is_bullish() && is_long_body(candle) &&
+            candle.close > previous.close &&
+            candle.open > previous.open && candle.open <= previous.close &&
+            candle.upper_shadow() <= 0.3 * candle.body()
+    };
+    first.is_bullish() && is_long_body(first) && soldier(first, second) && soldier(second, today)
+}
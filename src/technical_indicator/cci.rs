@@ -0,0 +1,75 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+use crate::utils::rolling_window::RollingWindow;
+
+/// Lambert's constant, scales the index so most values fall between -100 and 100.
+const CCI_SCALE: f32 = 0.015;
+
+/// Commodity Channel Index: deviation of the typical price (high + low + close) / 3 from its
+/// `length` SMA, divided by the mean absolute deviation over the same bars.
+#[derive(Clone)]
+pub struct Cci {
+    typical_prices: RollingWindow<f32>,
+    current_value: f32
+}
+
+impl Cci {
+    pub fn new(length: usize) -> Self {
+        Self {
+            typical_prices: RollingWindow::new(length),
+            current_value: 0.0
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> f32 {
+        let typical_price = (high + low + close) / 3.0;
+        self.typical_prices.add(typical_price);
+
+        let count = self.typical_prices.len() as f32;
+        let mean = self.typical_prices.iter().sum::<f32>() / count;
+        let mean_deviation = self.typical_prices.iter().map(|price| (price - mean).abs()).sum::<f32>() / count;
+        self.current_value = if mean_deviation > 0.0 {
+            (typical_price - mean) / (CCI_SCALE * mean_deviation)
+        } else {
+            0.0
+        };
+        self.current_value
+    }
+
+    pub fn current(&self) -> f32 {
+        self.current_value
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.typical_prices.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.typical_prices.clear();
+        self.current_value = 0.0;
+    }
+}
+
+impl Indicator for Cci {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> f32 {
+        Cci::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Cci::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Cci::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.typical_prices.capacity()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::accumulation_distribution::money_flow_volume;
+use crate::technical_indicator::indicator::Indicator;
+use crate::utils::rolling_window::RollingWindow;
+
+/// Chaikin Money Flow: money flow volume of the last `length` bars divided by their volume,
+/// between -1 for closes at every low and 1 for closes at every high.
+#[derive(Clone)]
+pub struct ChaikinMoneyFlow {
+    bars: RollingWindow<(f32, f32)>,
+    money_flow_volume: f64,
+    volume: f64
+}
+
+impl ChaikinMoneyFlow {
+    pub fn new(length: usize) -> Self {
+        Self {
+            bars: RollingWindow::new(length),
+            money_flow_volume: 0.0,
+            volume: 0.0
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32, volume: f32) -> f32 {
+        let bar_money_flow_volume = money_flow_volume(high, low, close, volume);
+        self.money_flow_volume += bar_money_flow_volume as f64;
+        self.volume += volume as f64;
+        if let Some((evicted_money_flow_volume, evicted_volume)) = self.bars.add((bar_money_flow_volume, volume)) {
+            self.money_flow_volume -= evicted_money_flow_volume as f64;
+            self.volume -= evicted_volume as f64;
+        }
+        self.current()
+    }
+
+    pub fn current(&self) -> f32 {
+        if self.volume > 0.0 {
+            (self.money_flow_volume / self.volume).clamp(-1.0, 1.0) as f32
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.bars.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.bars.clear();
+        self.money_flow_volume = 0.0;
+        self.volume = 0.0;
+    }
+}
+
+impl Indicator for ChaikinMoneyFlow {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        ChaikinMoneyFlow::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        ChaikinMoneyFlow::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ChaikinMoneyFlow::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.bars.capacity()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ScalarOutput, ValueIndicator};
+
+/// `outer` computed on the main line of `inner`, e.g. an EMA of RSI:
+/// `Chained::new(Rsi::new(14), Ema::new(9))`. Chains nest, and the inner indicator can be any
+/// bar indicator, e.g. `Obv` or a `Sourced` one.
+#[derive(Clone)]
+pub struct Chained<A, B>
+where
+    A: Indicator,
+    A::Output: ScalarOutput,
+    B: ValueIndicator
+{
+    inner: A,
+    outer: B
+}
+
+impl<A, B> Chained<A, B>
+where
+    A: Indicator,
+    A::Output: ScalarOutput,
+    B: ValueIndicator
+{
+    pub fn new(inner: A, outer: B) -> Self {
+        Self {
+            inner,
+            outer
+        }
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+impl<A, B> Indicator for Chained<A, B>
+where
+    A: Indicator,
+    A::Output: ScalarOutput,
+    B: ValueIndicator
+{
+    type Output = B::Output;
+
+    /// The outer indicator only consumes inner values once the inner indicator is ready, so
+    /// its seed is not made of warm-up values.
+    fn update(&mut self, bar: &StockPriceInfo) -> B::Output {
+        let inner_value = self.inner.update(bar).value();
+        if self.inner.is_ready() {
+            self.outer.update_value(inner_value)
+        } else {
+            self.outer.current()
+        }
+    }
+
+    fn current(&self) -> B::Output {
+        self.outer.current()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready() && self.outer.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.outer.reset();
+    }
+
+    fn lookback(&self) -> usize {
+        self.inner.lookback() + self.outer.lookback() - 1
+    }
+}
+
+impl<A, B> ValueIndicator for Chained<A, B>
+where
+    A: ValueIndicator,
+    A::Output: ScalarOutput,
+    B: ValueIndicator
+{
+    fn update_value(&mut self, value: f32) -> B::Output {
+        let inner_value = self.inner.update_value(value).value();
+        if self.inner.is_ready() {
+            self.outer.update_value(inner_value)
+        } else {
+            self.outer.current()
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ScalarOutput};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Indicator whose value can be checked for divergences against price.
+pub trait OscillatorSource: Clone {
+    fn oscillator_value(&mut self, stock_price_info: &StockPriceInfo) -> f32;
+    fn reset_oscillator(&mut self);
+}
+
+/// Any indicator with a main line works, e.g. the MACD line, the RSI or a volume indicator like OBV.
+impl<I> OscillatorSource for I
+where
+    I: Indicator + Clone,
+    I::Output: ScalarOutput
+{
+    fn oscillator_value(&mut self, stock_price_info: &StockPriceInfo) -> f32 {
+        self.update(stock_price_info).value()
+    }
+
+    fn reset_oscillator(&mut self) {
+        self.reset()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DivergenceKind {
+    /// Price makes a lower low while the oscillator makes a higher low.
+    RegularBullish,
+    /// Price makes a higher low while the oscillator makes a lower low.
+    HiddenBullish,
+    /// Price makes a higher high while the oscillator makes a lower high.
+    RegularBearish,
+    /// Price makes a lower high while the oscillator makes a higher high.
+    HiddenBearish
+}
+
+impl DivergenceKind {
+    pub fn is_bullish(&self) -> bool {
+        matches!(self, DivergenceKind::RegularBullish | DivergenceKind::HiddenBullish)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Pivot {
+    pub bar_index: usize,
+    pub price: f32,
+    pub oscillator: f32
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Divergence {
+    pub kind: DivergenceKind,
+    pub previous_pivot: Pivot,
+    pub pivot: Pivot,
+    /// Price change between the pivots relative to the previous pivot's price.
+    pub price_change: f32,
+    /// Oscillator change between the pivots relative to the oscillator's range (highest minus
+    /// lowest value) over the last `max_pivot_distance` bars, so that the threshold means the
+    /// same for oscillators centred on zero (MACD), bounded ones (RSI) and cumulative ones (OBV).
+    pub oscillator_change: f32
+}
+
+#[derive(Clone, Copy)]
+struct Bar {
+    low: f32,
+    high: f32,
+    oscillator: f32
+}
+
+/// Finds swing pivots that have `left_bars` higher lows (lower highs) before them and `right_bars`
+/// after them, and compares each pivot with the previous one of the same type. A pivot can only
+/// be confirmed `right_bars` bars after it happened, so divergences are reported with that delay.
+#[derive(Clone)]
+pub struct DivergenceDetector {
+    left_bars: usize,
+    right_bars: usize,
+    min_price_change: f32,
+    min_oscillator_change: f32,
+    max_pivot_distance: usize,
+    bars: RollingWindow<Bar>,
+    oscillator_history: RollingWindow<f32>,
+    bar_index: usize,
+    last_low_pivot: Option<Pivot>,
+    last_high_pivot: Option<Pivot>
+}
+
+impl DivergenceDetector {
+    pub fn new(left_bars: usize,
+               right_bars: usize,
+               min_price_change: f32,
+               min_oscillator_change: f32,
+               max_pivot_distance: usize) -> Self {
+        Self {
+            left_bars,
+            right_bars,
+            min_price_change,
+            min_oscillator_change,
+            max_pivot_distance,
+            bars: RollingWindow::new(left_bars + right_bars + 1),
+            oscillator_history: RollingWindow::new(max_pivot_distance + right_bars + 1),
+            bar_index: 0,
+            last_low_pivot: None,
+            last_high_pivot: None
+        }
+    }
+
+    pub fn next(&mut self, low: f32, high: f32, oscillator: f32) -> Option<Divergence> {
+        self.bars.add(Bar { low, high, oscillator });
+        self.oscillator_history.add(oscillator);
+        self.bar_index += 1;
+        if !self.bars.is_full() {
+            return None
+        }
+
+        let candidate = *self.bars.get(self.left_bars)?;
+        let candidate_index = self.bar_index - 1 - self.right_bars;
+        let mut divergence = None;
+
+        if self.is_pivot(|bar| bar.low, |candidate, other| candidate < other, |candidate, other| candidate <= other) {
+            let pivot = Pivot { bar_index: candidate_index, price: candidate.low, oscillator: candidate.oscillator };
+            divergence = self.compare_lows(pivot);
+            self.last_low_pivot = Some(pivot);
+        }
+        if self.is_pivot(|bar| bar.high, |candidate, other| candidate > other, |candidate, other| candidate >= other) {
+            let pivot = Pivot { bar_index: candidate_index, price: candidate.high, oscillator: candidate.oscillator };
+            divergence = divergence.or(self.compare_highs(pivot));
+            self.last_high_pivot = Some(pivot);
+        }
+        divergence
+    }
+
+    pub fn reset(&mut self) {
+        self.bars.clear();
+        self.oscillator_history.clear();
+        self.bar_index = 0;
+        self.last_low_pivot = None;
+        self.last_high_pivot = None;
+    }
+
+    fn is_pivot<V, L, R>(&self, value: V, beats_left: L, beats_right: R) -> bool
+    where
+        V: Fn(&Bar) -> f32,
+        L: Fn(f32, f32) -> bool,
+        R: Fn(f32, f32) -> bool
+    {
+        let candidate = match self.bars.get(self.left_bars) {
+            Some(bar) => value(bar),
+            None => return false
+        };
+        self.bars.iter().enumerate().all(|(i, bar)| {
+            if i < self.left_bars {
+                beats_left(candidate, value(bar))
+            } else if i > self.left_bars {
+                beats_right(candidate, value(bar))
+            } else {
+                true
+            }
+        })
+    }
+
+    fn compare_lows(&self, pivot: Pivot) -> Option<Divergence> {
+        let previous_pivot = self.recent_pivot(self.last_low_pivot, &pivot)?;
+        let (price_change, oscillator_change) = self.changes(&previous_pivot, &pivot);
+        let kind = if price_change < -self.min_price_change && oscillator_change > self.min_oscillator_change {
+            DivergenceKind::RegularBullish
+        } else if price_change > self.min_price_change && oscillator_change < -self.min_oscillator_change {
+            DivergenceKind::HiddenBullish
+        } else {
+            return None
+        };
+        Some(Divergence { kind, previous_pivot, pivot, price_change, oscillator_change })
+    }
+
+    fn compare_highs(&self, pivot: Pivot) -> Option<Divergence> {
+        let previous_pivot = self.recent_pivot(self.last_high_pivot, &pivot)?;
+        let (price_change, oscillator_change) = self.changes(&previous_pivot, &pivot);
+        let kind = if price_change > self.min_price_change && oscillator_change < -self.min_oscillator_change {
+            DivergenceKind::RegularBearish
+        } else if price_change < -self.min_price_change && oscillator_change > self.min_oscillator_change {
+            DivergenceKind::HiddenBearish
+        } else {
+            return None
+        };
+        Some(Divergence { kind, previous_pivot, pivot, price_change, oscillator_change })
+    }
+
+    fn recent_pivot(&self, previous_pivot: Option<Pivot>, pivot: &Pivot) -> Option<Pivot> {
+        previous_pivot.filter(|previous| pivot.bar_index - previous.bar_index <= self.max_pivot_distance)
+    }
+
+    fn changes(&self, previous_pivot: &Pivot, pivot: &Pivot) -> (f32, f32) {
+        let price_change = (pivot.price - previous_pivot.price) / previous_pivot.price;
+        let (lowest, highest) = self.oscillator_history.iter()
+            .fold((f32::MAX, f32::MIN), |(lowest, highest), &value| (lowest.min(value), highest.max(value)));
+        let oscillator_range = f32::max(highest - lowest, f32::EPSILON);
+        let oscillator_change = (pivot.oscillator - previous_pivot.oscillator) / oscillator_range;
+        (price_change, oscillator_change)
+    }
+}
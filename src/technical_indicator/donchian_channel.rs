@@ -0,0 +1,92 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Highest high and lowest low of the last `length` bars, including the latest one.
+#[derive(Clone)]
+pub struct DonchianChannel {
+    highs: RollingWindow<f32>,
+    lows: RollingWindow<f32>
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DonchianChannelResult {
+    pub upper_band: f32,
+    pub lower_band: f32
+}
+
+impl DonchianChannelResult {
+    pub fn middle_band(&self) -> f32 {
+        (self.upper_band + self.lower_band) / 2.0
+    }
+}
+
+impl From<DonchianChannelResult> for Vec<f32> {
+    fn from(result: DonchianChannelResult) -> Self {
+        vec![result.lower_band, result.middle_band(), result.upper_band]
+    }
+}
+
+impl DonchianChannel {
+    pub fn new(length: usize) -> Self {
+        Self {
+            highs: RollingWindow::new(length),
+            lows: RollingWindow::new(length)
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32) -> DonchianChannelResult {
+        self.highs.add(high);
+        self.lows.add(low);
+        self.current()
+    }
+
+    /// Channel of the bars seen so far. Breakout rules compare today's bar with the channel
+    /// before it is updated with today.
+    pub fn current(&self) -> DonchianChannelResult {
+        DonchianChannelResult {
+            upper_band: self.highs.iter().cloned().fold(f32::NAN, f32::max),
+            lower_band: self.lows.iter().cloned().fold(f32::NAN, f32::min)
+        }
+    }
+
+    /// Whether the channel already spans `length` bars.
+    pub fn is_ready(&self) -> bool {
+        self.highs.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+impl IndicatorOutput for DonchianChannelResult {
+    fn to_values(&self) -> Vec<f32> {
+        Vec::from(*self)
+    }
+}
+
+impl Indicator for DonchianChannel {
+    type Output = DonchianChannelResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> DonchianChannelResult {
+        self.next(bar.high, bar.low)
+    }
+
+    fn current(&self) -> DonchianChannelResult {
+        DonchianChannel::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        DonchianChannel::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        DonchianChannel::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.highs.capacity()
+    }
+}
@@ -1,16 +1,43 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+
+/// Starting value of an exponential average, which otherwise needs many bars to forget it.
+/// `Ema::new` keeps the original zero seed, the other seeds are opted into with `with_seed`.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum EmaSeed {
+    /// Starts at the first value.
+    FirstValue,
+    /// Simple average of the first `length` values, then exponential smoothing.
+    Sma,
+    /// Starts at 0 and ramps up towards the prices, as the EMA originally did.
+    #[default]
+    Zero
+}
+
+#[derive(Clone, Debug)]
 pub struct Ema {
     length: usize,
     current_ema: f32,
+    samples: usize,
+    seed: EmaSeed
 }
 
 impl Ema {
     pub fn new(length: usize) -> Self {
-        Self { length, current_ema: 0.0f32 }
+        Self::with_seed(length, EmaSeed::default())
+    }
+
+    pub fn with_seed(length: usize, seed: EmaSeed) -> Self {
+        Self { length, current_ema: 0.0f32, samples: 0, seed }
     }
 
     pub fn next(&mut self, price: f32) -> f32 {
-        let new_ema = price * self.k_param() + self.current_ema * (1.0f32 - self.k_param());
-        self.current_ema = new_ema;
+        self.current_ema = match self.seed {
+            EmaSeed::FirstValue if self.samples == 0 => price,
+            EmaSeed::Sma if self.samples < self.length => self.current_ema + (price - self.current_ema) / (self.samples + 1) as f32,
+            _ => price * self.k_param() + self.current_ema * (1.0f32 - self.k_param())
+        };
+        self.samples += 1;
         return self.current_ema;
     }
 
@@ -18,7 +45,66 @@ impl Ema {
         return self.current_ema
     }
 
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn seed(&self) -> EmaSeed {
+        self.seed
+    }
+
+    /// Number of values until the seed is complete: one for `FirstValue`, `length` otherwise.
+    pub fn seed_period(&self) -> usize {
+        match self.seed {
+            EmaSeed::FirstValue => 1,
+            EmaSeed::Sma | EmaSeed::Zero => self.length
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.samples >= self.seed_period()
+    }
+
+    pub fn reset(&mut self) {
+        self.current_ema = 0.0f32;
+        self.samples = 0;
+    }
+
     fn k_param(&self) -> f32 {
         2.0f32 / ((self.length as f32) + 1.0f32)
     }
-}
\ No newline at end of file
+}
+
+impl Indicator for Ema {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> f32 {
+        Ema::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Ema::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Ema::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.seed_period()
+    }
+}
+
+impl ValueIndicator for Ema {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
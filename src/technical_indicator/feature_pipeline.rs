@@ -0,0 +1,167 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::atr::Atr;
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::indicator::Indicator;
+use crate::technical_indicator::macd::Macd;
+use crate::technical_indicator::percent_off_ath::PercentOffAth;
+use crate::technical_indicator::rsi::Rsi;
+
+/// Model input column. Price based values are normalised by the close so a model trained on
+/// one ticker can be applied to another.
+#[derive(Clone, Copy, Debug)]
+pub enum Feature {
+    /// (close - EMA) / EMA
+    EmaDistance(usize),
+    Rsi(usize),
+    /// MACD line / close
+    MacdLine { slow_period: usize, fast_period: usize, signal_period: usize },
+    /// (MACD line - signal line) / close
+    MacdHistogram { slow_period: usize, fast_period: usize, signal_period: usize },
+    /// ATR / close
+    Atr(usize),
+    PercentOffAth
+}
+
+#[derive(Clone)]
+enum FeatureCalculator {
+    EmaDistance(Ema),
+    Rsi(Rsi),
+    MacdLine(Macd),
+    MacdHistogram(Macd),
+    Atr(Atr),
+    PercentOffAth(PercentOffAth)
+}
+
+impl FeatureCalculator {
+    /// The averages are seeded with a simple average, a zero seed would skew the first features.
+    fn new(feature: Feature) -> Self {
+        match feature {
+            Feature::EmaDistance(length) => FeatureCalculator::EmaDistance(Ema::with_seed(length, EmaSeed::Sma)),
+            Feature::Rsi(length) => FeatureCalculator::Rsi(Rsi::new(length)),
+            Feature::MacdLine { slow_period, fast_period, signal_period } =>
+                FeatureCalculator::MacdLine(Macd::with_seed(slow_period, fast_period, signal_period, EmaSeed::Sma)),
+            Feature::MacdHistogram { slow_period, fast_period, signal_period } =>
+                FeatureCalculator::MacdHistogram(Macd::with_seed(slow_period, fast_period, signal_period, EmaSeed::Sma)),
+            Feature::Atr(length) => FeatureCalculator::Atr(Atr::new(length)),
+            Feature::PercentOffAth => FeatureCalculator::PercentOffAth(PercentOffAth::new())
+        }
+    }
+
+    fn next(&mut self, today: &StockPriceInfo, yesterday_close: f32) -> f32 {
+        match self {
+            FeatureCalculator::EmaDistance(ema) => {
+                let ema = ema.next(today.close);
+                (today.close - ema) / ema
+            }
+            FeatureCalculator::Rsi(rsi) => rsi.next(today.close).rsi_line,
+            FeatureCalculator::MacdLine(macd) => macd.next(today.close).macd_line / today.close,
+            FeatureCalculator::MacdHistogram(macd) => {
+                let macd = macd.next(today.close);
+                (macd.macd_line - macd.signal_line) / today.close
+            }
+            FeatureCalculator::Atr(atr) => atr.next(today.high, today.low, yesterday_close) / today.close,
+            FeatureCalculator::PercentOffAth(percent_off_ath) => percent_off_ath.next(today.close)
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match self {
+            FeatureCalculator::EmaDistance(ema) => Indicator::is_ready(ema),
+            FeatureCalculator::Rsi(rsi) => Indicator::is_ready(rsi),
+            FeatureCalculator::MacdLine(macd) | FeatureCalculator::MacdHistogram(macd) => Indicator::is_ready(macd),
+            FeatureCalculator::Atr(atr) => Indicator::is_ready(atr),
+            FeatureCalculator::PercentOffAth(percent_off_ath) => Indicator::is_ready(percent_off_ath)
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        match self {
+            FeatureCalculator::EmaDistance(ema) => Indicator::lookback(ema),
+            FeatureCalculator::Rsi(rsi) => Indicator::lookback(rsi),
+            FeatureCalculator::MacdLine(macd) | FeatureCalculator::MacdHistogram(macd) => Indicator::lookback(macd),
+            FeatureCalculator::Atr(atr) => Indicator::lookback(atr),
+            FeatureCalculator::PercentOffAth(percent_off_ath) => Indicator::lookback(percent_off_ath)
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            FeatureCalculator::EmaDistance(ema) => ema.reset(),
+            FeatureCalculator::Rsi(rsi) => rsi.reset(),
+            FeatureCalculator::MacdLine(macd) | FeatureCalculator::MacdHistogram(macd) => macd.reset(),
+            FeatureCalculator::Atr(atr) => atr.reset(),
+            FeatureCalculator::PercentOffAth(percent_off_ath) => percent_off_ath.reset()
+        }
+    }
+}
+
+/// Turns every bar into a feature vector with one value per configured `Feature`, in order.
+#[derive(Clone)]
+pub struct FeaturePipeline {
+    calculators: Vec<FeatureCalculator>,
+    previous_close: Option<f32>,
+    features: Vec<f32>
+}
+
+impl FeaturePipeline {
+    pub fn new(features: &[Feature]) -> Self {
+        Self {
+            calculators: features.iter().map(|feature| FeatureCalculator::new(*feature)).collect(),
+            previous_close: None,
+            features: vec![]
+        }
+    }
+
+    pub fn next(&mut self, today: &StockPriceInfo, yesterday: &Option<StockPriceInfo>) -> Vec<f32> {
+        let yesterday_close = yesterday.as_ref().map(|yesterday| yesterday.close).unwrap_or(today.close);
+        self.next_with_previous_close(today, yesterday_close)
+    }
+
+    fn next_with_previous_close(&mut self, today: &StockPriceInfo, yesterday_close: f32) -> Vec<f32> {
+        self.previous_close = Some(today.close);
+        self.features = self.calculators.iter_mut()
+            .map(|calculator| calculator.next(today, yesterday_close))
+            .collect();
+        self.features.clone()
+    }
+
+    /// Whether every indicator is warmed up.
+    pub fn is_ready(&self) -> bool {
+        self.calculators.iter().all(|calculator| calculator.is_ready())
+    }
+
+    pub fn reset(&mut self) {
+        self.calculators.iter_mut().for_each(|calculator| calculator.reset());
+        self.previous_close = None;
+        self.features.clear();
+    }
+
+    pub fn width(&self) -> usize {
+        self.calculators.len()
+    }
+}
+
+impl Indicator for FeaturePipeline {
+    type Output = Vec<f32>;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> Vec<f32> {
+        let yesterday_close = self.previous_close.unwrap_or(bar.close);
+        self.next_with_previous_close(bar, yesterday_close)
+    }
+
+    fn current(&self) -> Vec<f32> {
+        self.features.clone()
+    }
+
+    fn is_ready(&self) -> bool {
+        FeaturePipeline::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        FeaturePipeline::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.calculators.iter().map(|calculator| calculator.lookback()).max().unwrap_or(0)
+    }
+}
@@ -0,0 +1,151 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::donchian_channel::DonchianChannel;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Ichimoku Kinko Hyo. Every value reported for a bar is known at its close:
+/// - the cloud under today's bar is the Senkou spans computed `displacement` bars ago, while the
+///   spans computed today are reported as the leading cloud that will be plotted ahead,
+/// - the Chikou span, today's close plotted `displacement` bars back, is reported today together
+///   with the close it is compared against instead of being written into past bars.
+#[derive(Clone)]
+pub struct Ichimoku {
+    tenkan: DonchianChannel,
+    kijun: DonchianChannel,
+    senkou_b: DonchianChannel,
+    displaced_spans: RollingWindow<(f32, f32)>,
+    closes: RollingWindow<f32>,
+    samples: usize
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IchimokuResult {
+    /// Conversion line, midpoint of the `tenkan_length` range.
+    pub tenkan_sen: f32,
+    /// Base line, midpoint of the `kijun_length` range.
+    pub kijun_sen: f32,
+    /// Cloud under today's bar.
+    pub senkou_span_a: f32,
+    pub senkou_span_b: f32,
+    /// Cloud computed today, plotted `displacement` bars ahead.
+    pub leading_senkou_span_a: f32,
+    pub leading_senkou_span_b: f32,
+    /// Today's close, plotted `displacement` bars back.
+    pub chikou_span: f32,
+    /// Close of the bar the Chikou span is plotted against.
+    pub chikou_reference_close: f32
+}
+
+impl IchimokuResult {
+    pub fn cloud_top(&self) -> f32 {
+        f32::max(self.senkou_span_a, self.senkou_span_b)
+    }
+
+    pub fn cloud_bottom(&self) -> f32 {
+        f32::min(self.senkou_span_a, self.senkou_span_b)
+    }
+
+    /// Whether the cloud ahead is green, i.e. Senkou A above Senkou B.
+    pub fn leading_cloud_bullish(&self) -> bool {
+        self.leading_senkou_span_a > self.leading_senkou_span_b
+    }
+}
+
+impl Ichimoku {
+    pub fn new(tenkan_length: usize, kijun_length: usize, senkou_b_length: usize, displacement: usize) -> Self {
+        Self {
+            tenkan: DonchianChannel::new(tenkan_length),
+            kijun: DonchianChannel::new(kijun_length),
+            senkou_b: DonchianChannel::new(senkou_b_length),
+            displaced_spans: RollingWindow::new(displacement + 1),
+            closes: RollingWindow::new(displacement + 1),
+            samples: 0
+        }
+    }
+
+    /// 9, 26, 52 with a 26-bar displacement.
+    pub fn standard() -> Self {
+        Self::new(9, 26, 52, 26)
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> IchimokuResult {
+        let tenkan_sen = self.tenkan.next(high, low).middle_band();
+        let kijun_sen = self.kijun.next(high, low).middle_band();
+        let senkou_span_b = self.senkou_b.next(high, low).middle_band();
+        self.displaced_spans.add(((tenkan_sen + kijun_sen) / 2.0, senkou_span_b));
+        self.closes.add(close);
+        self.samples += 1;
+        self.current()
+    }
+
+    pub fn current(&self) -> IchimokuResult {
+        let (senkou_span_a, senkou_span_b) = self.displaced_spans.get(0).cloned().unwrap_or_default();
+        let (leading_senkou_span_a, leading_senkou_span_b) = self.displaced_spans.iter().last().cloned().unwrap_or_default();
+        IchimokuResult {
+            tenkan_sen: self.tenkan.current().middle_band(),
+            kijun_sen: self.kijun.current().middle_band(),
+            senkou_span_a,
+            senkou_span_b,
+            leading_senkou_span_a,
+            leading_senkou_span_b,
+            chikou_span: self.closes.iter().last().cloned().unwrap_or_default(),
+            chikou_reference_close: self.closes.get(0).cloned().unwrap_or_default()
+        }
+    }
+
+    /// Close of the last consumed bar.
+    pub fn last_close(&self) -> Option<f32> {
+        self.closes.iter().last().cloned()
+    }
+
+    /// The displaced cloud under today's bar was computed from a full Senkou B range.
+    pub fn is_ready(&self) -> bool {
+        self.samples >= Indicator::lookback(self)
+    }
+
+    pub fn reset(&mut self) {
+        self.tenkan.reset();
+        self.kijun.reset();
+        self.senkou_b.reset();
+        self.displaced_spans.clear();
+        self.closes.clear();
+        self.samples = 0;
+    }
+}
+
+impl IndicatorOutput for IchimokuResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.tenkan_sen,
+             self.kijun_sen,
+             self.senkou_span_a,
+             self.senkou_span_b,
+             self.leading_senkou_span_a,
+             self.leading_senkou_span_b,
+             self.chikou_span,
+             self.chikou_reference_close]
+    }
+}
+
+impl Indicator for Ichimoku {
+    type Output = IchimokuResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> IchimokuResult {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> IchimokuResult {
+        Ichimoku::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Ichimoku::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Ichimoku::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.senkou_b.lookback() + self.displaced_spans.capacity() - 1
+    }
+}
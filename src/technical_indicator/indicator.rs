@@ -0,0 +1,97 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+
+/// Common interface of the technical indicators: each one consumes daily bars and keeps whatever
+/// history it needs itself (e.g. the previous close for the true range).
+pub trait Indicator {
+    type Output: IndicatorOutput;
+
+    /// Consumes the next bar and returns the updated value.
+    fn update(&mut self, bar: &StockPriceInfo) -> Self::Output;
+    /// Value after the last consumed bar.
+    fn current(&self) -> Self::Output;
+    /// Whether enough bars were consumed for the value to be meaningful.
+    fn is_ready(&self) -> bool;
+    fn reset(&mut self);
+    /// Number of bars needed before the indicator is ready.
+    fn lookback(&self) -> usize;
+
+    /// Runs the indicator from scratch over a whole history, one value per bar.
+    fn compute_series(&mut self, bars: &[StockPriceInfo]) -> Vec<Self::Output> {
+        self.reset();
+        bars.iter().map(|bar| self.update(bar)).collect()
+    }
+
+    /// Like `compute_series`, keeping only the main line as a column of values.
+    fn compute_column(&mut self, bars: &[StockPriceInfo]) -> Vec<f32> where Self::Output: ScalarOutput {
+        self.reset();
+        bars.iter().map(|bar| self.update(bar).value()).collect()
+    }
+}
+
+/// Indicator of a single series of values, which can therefore be computed on any price source
+/// or on the output of another indicator, see `Sourced` and `Chained`.
+pub trait ValueIndicator: Indicator {
+    fn update_value(&mut self, value: f32) -> Self::Output;
+}
+
+/// Output with a main line that can feed another indicator.
+pub trait ScalarOutput {
+    fn value(&self) -> f32;
+}
+
+impl ScalarOutput for f32 {
+    fn value(&self) -> f32 {
+        *self
+    }
+}
+
+/// Flattens an indicator value into CSV columns.
+pub trait IndicatorOutput: Clone {
+    fn to_values(&self) -> Vec<f32>;
+}
+
+impl IndicatorOutput for f32 {
+    fn to_values(&self) -> Vec<f32> {
+        vec![*self]
+    }
+}
+
+impl IndicatorOutput for Vec<f32> {
+    fn to_values(&self) -> Vec<f32> {
+        self.clone()
+    }
+}
+
+impl<A: IndicatorOutput, B: IndicatorOutput> IndicatorOutput for (A, B) {
+    fn to_values(&self) -> Vec<f32> {
+        let mut values = self.0.to_values();
+        values.extend(self.1.to_values());
+        values
+    }
+}
+
+/// Two indicators fed with the same bars, e.g. `(Ema::new(20), Atr::new(14))`.
+impl<A: Indicator, B: Indicator> Indicator for (A, B) {
+    type Output = (A::Output, B::Output);
+
+    fn update(&mut self, bar: &StockPriceInfo) -> Self::Output {
+        (self.0.update(bar), self.1.update(bar))
+    }
+
+    fn current(&self) -> Self::Output {
+        (self.0.current(), self.1.current())
+    }
+
+    fn is_ready(&self) -> bool {
+        self.0.is_ready() && self.1.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+        self.1.reset();
+    }
+
+    fn lookback(&self) -> usize {
+        usize::max(self.0.lookback(), self.1.lookback())
+    }
+}
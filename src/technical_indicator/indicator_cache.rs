@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use chrono::NaiveDate;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ScalarOutput};
+
+/// Identifies an indicator series: the bars it was computed on and the indicator itself, by the
+/// `Debug` representation of its freshly reset state, so that every setting (length, seed, price
+/// source...) is part of the key. The bars are told apart by ticker, count and date range, so
+/// that e.g. a date-filtered slice of a ticker doesn't get the column of its whole history.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IndicatorKey {
+    ticker: String,
+    indicator: String,
+    bar_count: usize,
+    first_date: Option<NaiveDate>,
+    last_date: Option<NaiveDate>
+}
+
+impl IndicatorKey {
+    pub fn new<I: Indicator + Debug>(ticker: &str, indicator: &mut I, bars: &[StockPriceInfo]) -> Self {
+        indicator.reset();
+        Self {
+            ticker: ticker.to_string(),
+            indicator: format!("{:?}", indicator),
+            bar_count: bars.len(),
+            first_date: bars.first().map(|bar| bar.date),
+            last_date: bars.last().map(|bar| bar.date)
+        }
+    }
+}
+
+/// Slot filled by the first thread requesting the series.
+type CachedSeries = Arc<OnceLock<Arc<Vec<f32>>>>;
+
+/// Indicator columns shared between the evaluations of a grid search, so that e.g. the EMA of a
+/// ticker is computed once per length instead of once per combination of the other parameters.
+/// Safe to use from rayon workers: a series requested by several threads at once is still
+/// computed only once, the others wait for it.
+#[derive(Default)]
+pub struct IndicatorCache {
+    series: Mutex<HashMap<IndicatorKey, CachedSeries>>,
+    lookups: AtomicUsize,
+    computations: AtomicUsize
+}
+
+impl IndicatorCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached series, computing it with `compute` the first time it is requested.
+    pub fn get_or_compute<F>(&self, key: IndicatorKey, compute: F) -> Arc<Vec<f32>>
+        where F: FnOnce() -> Vec<f32> {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        // The map lock is only held to find the slot, computing happens outside of it.
+        let slot = self.series.lock().unwrap().entry(key).or_default().clone();
+        slot.get_or_init(|| {
+            self.computations.fetch_add(1, Ordering::Relaxed);
+            Arc::new(compute())
+        }).clone()
+    }
+
+    /// Main line of `indicator` over the whole `bars` of `ticker`, e.g.
+    /// `cache.column(ticker, Ema::new(length), bars)`.
+    pub fn column<I>(&self, ticker: &str, mut indicator: I, bars: &[StockPriceInfo]) -> Arc<Vec<f32>>
+        where I: Indicator + Debug,
+              I::Output: ScalarOutput {
+        let key = IndicatorKey::new(ticker, &mut indicator, bars);
+        self.get_or_compute(key, || indicator.compute_column(bars))
+    }
+
+    /// Number of distinct series held.
+    pub fn len(&self) -> usize {
+        self.series.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of requests served so far, cached or not.
+    pub fn lookups(&self) -> usize {
+        self.lookups.load(Ordering::Relaxed)
+    }
+
+    /// Number of series actually computed so far.
+    pub fn computations(&self) -> usize {
+        self.computations.load(Ordering::Relaxed)
+    }
+
+    pub fn clear(&self) {
+        self.series.lock().unwrap().clear();
+    }
+}
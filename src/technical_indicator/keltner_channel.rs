@@ -1,6 +1,10 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
 use crate::technical_indicator::atr::Atr;
-use crate::technical_indicator::ema::Ema;
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::smoothing::Smoothing;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
 
+#[derive(Clone)]
 pub struct KeltnerChannel {
     channel_size: f32,
     ema: Ema,
@@ -23,9 +27,18 @@ impl Into<Vec<f32>> for KeltnerChannelResult {
 impl KeltnerChannel {
     pub fn new(length: usize, channel_size: f32) -> Self {
         Self {
-            channel_size,
             ema: Ema::new(length),
             atr: Atr::new(length),
+            channel_size
+        }
+    }
+
+    /// Seeds both the middle EMA and the ATR average with `seed`.
+    pub fn with_seed(length: usize, channel_size: f32, seed: EmaSeed) -> Self {
+        Self {
+            channel_size,
+            ema: Ema::with_seed(length, seed),
+            atr: Atr::with_smoothing_and_seed(length, Smoothing::Wilder, seed),
         }
     }
 
@@ -47,4 +60,41 @@ impl KeltnerChannel {
         }
     }
 
+    pub fn reset(&mut self) {
+        self.ema.reset();
+        self.atr.reset();
+    }
+
+}
+
+impl IndicatorOutput for KeltnerChannelResult {
+    fn to_values(&self) -> Vec<f32> {
+        self.clone().into()
+    }
+}
+
+impl Indicator for KeltnerChannel {
+    type Output = KeltnerChannelResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> KeltnerChannelResult {
+        self.ema.next(bar.close);
+        self.atr.update(bar);
+        KeltnerChannel::current(self)
+    }
+
+    fn current(&self) -> KeltnerChannelResult {
+        KeltnerChannel::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema.is_ready() && self.atr.is_ready()
+    }
+
+    fn reset(&mut self) {
+        KeltnerChannel::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        usize::max(self.ema.lookback(), self.atr.lookback())
+    }
 }
\ No newline at end of file
@@ -1,5 +1,8 @@
-use crate::technical_indicator::ema::Ema;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput, ScalarOutput, ValueIndicator};
 
+#[derive(Clone)]
 pub struct Macd {
     slow_period_ema: Ema,
     fast_period_ema: Ema,
@@ -14,29 +17,88 @@ pub struct MACDResult {
 
 impl Macd {
     pub fn new(slow_period:usize, fast_period: usize, signal_period: usize) -> Self {
+        Self::with_seed(slow_period, fast_period, signal_period, EmaSeed::Zero)
+    }
+
+    /// All three EMAs use `seed`. Except with the legacy `EmaSeed::Zero`, the signal line is
+    /// only fed once the slow EMA is seeded, so it is seeded from actual MACD values.
+    pub fn with_seed(slow_period:usize, fast_period: usize, signal_period: usize, seed: EmaSeed) -> Self {
         Self {
-            slow_period_ema: Ema::new(slow_period),
-            fast_period_ema: Ema::new(fast_period),
-            signal_period_ema: Ema::new(signal_period)
+            slow_period_ema: Ema::with_seed(slow_period, seed),
+            fast_period_ema: Ema::with_seed(fast_period, seed),
+            signal_period_ema: Ema::with_seed(signal_period, seed)
         }
     }
 
     pub fn default() -> Self {
-        Self {
-            slow_period_ema: Ema::new(26),
-            fast_period_ema: Ema::new(12),
-            signal_period_ema: Ema::new(9)
-        }
+        Self::new(26, 12, 9)
     }
 
     pub fn next(&mut self, price: f32) -> MACDResult {
         let fast_ema = self.fast_period_ema.next(price);
         let slow_ema = self.slow_period_ema.next(price);
         let macd_line = fast_ema - slow_ema;
-        let signal_line = self.signal_period_ema.next(macd_line);
+        let signal_line = if self.slow_period_ema.seed() == EmaSeed::Zero || self.slow_period_ema.is_ready() {
+            self.signal_period_ema.next(macd_line)
+        } else {
+            self.signal_period_ema.current()
+        };
         MACDResult {
             macd_line,
             signal_line
         }
     }
-}
\ No newline at end of file
+
+    pub fn reset(&mut self) {
+        self.slow_period_ema.reset();
+        self.fast_period_ema.reset();
+        self.signal_period_ema.reset();
+    }
+}
+
+impl IndicatorOutput for MACDResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.macd_line, self.signal_line]
+    }
+}
+
+/// The MACD line, the signal line being its own smoothing.
+impl ScalarOutput for MACDResult {
+    fn value(&self) -> f32 {
+        self.macd_line
+    }
+}
+
+impl Indicator for Macd {
+    type Output = MACDResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> MACDResult {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> MACDResult {
+        MACDResult {
+            macd_line: self.fast_period_ema.current() - self.slow_period_ema.current(),
+            signal_line: self.signal_period_ema.current()
+        }
+    }
+
+    /// The signal line is only warmed up once it has seen `signal_period` MACD values of a warmed up slow EMA.
+    fn is_ready(&self) -> bool {
+        self.slow_period_ema.samples() >= Indicator::lookback(self)
+    }
+
+    fn reset(&mut self) {
+        Macd::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.slow_period_ema.lookback() + self.signal_period_ema.lookback() - 1
+    }
+}
+
+impl ValueIndicator for Macd {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
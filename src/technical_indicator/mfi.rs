@@ -0,0 +1,92 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+use crate::utils::rolling_window::RollingWindow;
+
+/// Money Flow Index, a volume weighted RSI: the money flow (typical price times volume) of each
+/// bar counts as positive when the typical price rose and as negative when it fell, and
+/// MFI = 100 - 100 / (1 + positive flow / negative flow) over the last `length` bars.
+#[derive(Clone)]
+pub struct Mfi {
+    money_flows: RollingWindow<(f32, f32)>,
+    positive_flow: f64,
+    negative_flow: f64,
+    previous_typical_price: Option<f32>
+}
+
+impl Mfi {
+    pub fn new(length: usize) -> Self {
+        Self {
+            money_flows: RollingWindow::new(length),
+            positive_flow: 0.0,
+            negative_flow: 0.0,
+            previous_typical_price: None
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32, volume: f32) -> f32 {
+        let typical_price = (high + low + close) / 3.0;
+        if let Some(previous_typical_price) = self.previous_typical_price {
+            let money_flow = typical_price * volume;
+            let flow = if typical_price > previous_typical_price {
+                (money_flow, 0.0)
+            } else if typical_price < previous_typical_price {
+                (0.0, money_flow)
+            } else {
+                (0.0, 0.0)
+            };
+            self.positive_flow += flow.0 as f64;
+            self.negative_flow += flow.1 as f64;
+            if let Some((positive, negative)) = self.money_flows.add(flow) {
+                self.positive_flow -= positive as f64;
+                self.negative_flow -= negative as f64;
+            }
+        }
+        self.previous_typical_price = Some(typical_price);
+        self.current()
+    }
+
+    pub fn current(&self) -> f32 {
+        let total_flow = self.positive_flow + self.negative_flow;
+        if total_flow <= 0.0 {
+            50.0
+        } else {
+            (100.0 * self.positive_flow / total_flow).clamp(0.0, 100.0) as f32
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.money_flows.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.money_flows.clear();
+        self.positive_flow = 0.0;
+        self.negative_flow = 0.0;
+        self.previous_typical_price = None;
+    }
+}
+
+impl Indicator for Mfi {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        Mfi::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Mfi::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Mfi::reset(self)
+    }
+
+    /// `length` typical price changes need one bar more.
+    fn lookback(&self) -> usize {
+        self.money_flows.capacity() + 1
+    }
+}
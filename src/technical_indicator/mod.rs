@@ -3,4 +3,34 @@ pub mod ema;
 pub mod keltner_channel;
 pub mod macd;
 pub mod rsi;
-pub mod percent_off_ath;
\ No newline at end of file
+pub mod percent_off_ath;
+pub mod divergence;
+pub mod candlestick_patterns;
+pub mod feature_pipeline;
+pub mod donchian_channel;
+pub mod indicator;
+pub mod sma;
+pub mod standard_deviation;
+pub mod bollinger_bands;
+pub mod stochastic;
+pub mod williams_r;
+pub mod cci;
+pub mod mfi;
+pub mod wilder_average;
+pub mod adx;
+pub mod aroon;
+pub mod parabolic_sar;
+pub mod supertrend;
+pub mod obv;
+pub mod accumulation_distribution;
+pub mod chaikin_money_flow;
+pub mod vwap;
+pub mod relative_volume;
+pub mod ichimoku;
+pub mod smoothing;
+#[cfg(test)]
+mod reference_values;
+pub mod price_source;
+pub mod chained;
+pub mod relative_strength;
+pub mod indicator_cache;
@@ -0,0 +1,61 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+
+/// On-Balance Volume: running total that adds the volume of up closes and subtracts the volume
+/// of down closes.
+#[derive(Clone, Default)]
+pub struct Obv {
+    obv: f32,
+    previous_close: Option<f32>
+}
+
+impl Obv {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next(&mut self, close: f32, volume: f32) -> f32 {
+        if let Some(previous_close) = self.previous_close {
+            if close > previous_close {
+                self.obv += volume;
+            } else if close < previous_close {
+                self.obv -= volume;
+            }
+        }
+        self.previous_close = Some(close);
+        self.obv
+    }
+
+    pub fn current(&self) -> f32 {
+        self.obv
+    }
+
+    pub fn reset(&mut self) {
+        self.obv = 0.0;
+        self.previous_close = None;
+    }
+}
+
+impl Indicator for Obv {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        Obv::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.previous_close.is_some()
+    }
+
+    fn reset(&mut self) {
+        Obv::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+}
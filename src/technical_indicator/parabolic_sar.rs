@@ -0,0 +1,146 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+
+/// Wilder's Parabolic SAR. The stop trails the extreme point of the current trend, closing in
+/// by an acceleration factor that starts at `acceleration_step`, grows by the same step with
+/// every new extreme and is capped at `max_acceleration` (0.02 and 0.2 originally). The trend
+/// reverses when a bar crosses the stop.
+#[derive(Clone)]
+pub struct ParabolicSar {
+    acceleration_step: f32,
+    max_acceleration: f32,
+    trend: Option<SarTrend>,
+    previous_bars: [Option<(f32, f32)>; 2]
+}
+
+#[derive(Clone, Copy)]
+struct SarTrend {
+    is_uptrend: bool,
+    sar: f32,
+    extreme_point: f32,
+    acceleration: f32
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParabolicSarResult {
+    /// Stop for the next bar.
+    pub sar: f32,
+    /// Stop that was in effect during the last bar, before a possible reversal.
+    pub stop_price: f32,
+    pub is_uptrend: bool,
+    /// Whether the last bar crossed the stop and reversed the trend.
+    pub reversed: bool
+}
+
+impl ParabolicSar {
+    pub fn new(acceleration_step: f32, max_acceleration: f32) -> Self {
+        Self {
+            acceleration_step,
+            max_acceleration,
+            trend: None,
+            previous_bars: [None, None]
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32) -> ParabolicSarResult {
+        let result = match (self.trend, self.previous_bars[0]) {
+            (Some(trend), Some(previous_bar)) => self.follow_trend(trend, previous_bar, high, low),
+            (None, Some((previous_high, previous_low))) => {
+                let is_uptrend = high + low >= previous_high + previous_low;
+                let trend = if is_uptrend {
+                    SarTrend { is_uptrend, sar: f32::min(low, previous_low), extreme_point: f32::max(high, previous_high), acceleration: self.acceleration_step }
+                } else {
+                    SarTrend { is_uptrend, sar: f32::max(high, previous_high), extreme_point: f32::min(low, previous_low), acceleration: self.acceleration_step }
+                };
+                self.trend = Some(trend);
+                ParabolicSarResult { sar: trend.sar, stop_price: trend.sar, is_uptrend, reversed: false }
+            }
+            _ => ParabolicSarResult { sar: low, stop_price: low, is_uptrend: true, reversed: false }
+        };
+        self.previous_bars = [Some((high, low)), self.previous_bars[0]];
+        result
+    }
+
+    fn follow_trend(&mut self, mut trend: SarTrend, previous_bar: (f32, f32), high: f32, low: f32) -> ParabolicSarResult {
+        let (previous_high, previous_low) = previous_bar;
+        let (older_high, older_low) = self.previous_bars[1].unwrap_or(previous_bar);
+        let mut stop_price = trend.sar + trend.acceleration * (trend.extreme_point - trend.sar);
+        // The stop may never enter the range of the two previous bars.
+        stop_price = if trend.is_uptrend {
+            stop_price.min(previous_low).min(older_low)
+        } else {
+            stop_price.max(previous_high).max(older_high)
+        };
+
+        let reversed = if trend.is_uptrend { low <= stop_price } else { high >= stop_price };
+        if reversed {
+            trend = SarTrend {
+                is_uptrend: !trend.is_uptrend,
+                sar: trend.extreme_point,
+                extreme_point: if trend.is_uptrend { low } else { high },
+                acceleration: self.acceleration_step
+            };
+        } else {
+            trend.sar = stop_price;
+            let new_extreme = if trend.is_uptrend { high > trend.extreme_point } else { low < trend.extreme_point };
+            if new_extreme {
+                trend.extreme_point = if trend.is_uptrend { high } else { low };
+                trend.acceleration = f32::min(trend.acceleration + self.acceleration_step, self.max_acceleration);
+            }
+        }
+        self.trend = Some(trend);
+
+        ParabolicSarResult {
+            sar: trend.sar,
+            stop_price,
+            is_uptrend: trend.is_uptrend,
+            reversed
+        }
+    }
+
+    pub fn current(&self) -> ParabolicSarResult {
+        match self.trend {
+            Some(trend) => ParabolicSarResult { sar: trend.sar, stop_price: trend.sar, is_uptrend: trend.is_uptrend, reversed: false },
+            None => ParabolicSarResult::default()
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.trend.is_some()
+    }
+
+    pub fn reset(&mut self) {
+        self.trend = None;
+        self.previous_bars = [None, None];
+    }
+}
+
+impl IndicatorOutput for ParabolicSarResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.sar, if self.is_uptrend { 1.0 } else { -1.0 }]
+    }
+}
+
+impl Indicator for ParabolicSar {
+    type Output = ParabolicSarResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> ParabolicSarResult {
+        self.next(bar.high, bar.low)
+    }
+
+    fn current(&self) -> ParabolicSarResult {
+        ParabolicSar::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        ParabolicSar::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        ParabolicSar::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        2
+    }
+}
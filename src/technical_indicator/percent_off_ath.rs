@@ -1,20 +1,104 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+use crate::utils::rolling_window::RollingWindow;
+
+#[derive(Clone, Copy)]
+pub enum HighReference {
+    AllTime,
+    /// Highest price of the last N bars, including today.
+    Rolling(usize)
+}
+
+#[derive(Clone)]
 pub struct PercentOffAth {
     ath: f32,
-    current_percent_off_ath: f32
+    current_percent_off_ath: f32,
+    rolling_highs: Option<RollingWindow<f32>>
 }
 
 impl PercentOffAth {
     pub fn new() -> Self {
         Self {
             ath: 0.0f32,
-            current_percent_off_ath: 0.0f32
+            current_percent_off_ath: 0.0f32,
+            rolling_highs: None
+        }
+    }
+
+    pub fn with_high_reference(high_reference: HighReference) -> Self {
+        match high_reference {
+            HighReference::AllTime => Self::new(),
+            HighReference::Rolling(length) => Self {
+                rolling_highs: Some(RollingWindow::new(length)),
+                ..Self::new()
+            }
         }
     }
 
     pub fn next(&mut self, price: f32) -> f32 {
-        self.ath = f32::max(self.ath, price);
+        self.next_with_high(price, price)
+    }
+
+    /// Updates the reference high with `high` and measures how far `price` is below it.
+    pub fn next_with_high(&mut self, high: f32, price: f32) -> f32 {
+        self.ath = match self.rolling_highs.as_mut() {
+            Some(rolling_highs) => {
+                rolling_highs.add(high);
+                rolling_highs.iter().cloned().fold(f32::MIN, f32::max)
+            }
+            None => f32::max(self.ath, high)
+        };
         self.current_percent_off_ath = ((self.ath - price) / self.ath) * 100.0;
         self.current_percent_off_ath
     }
 
-}
\ No newline at end of file
+    pub fn current(&self) -> f32 {
+        self.current_percent_off_ath
+    }
+
+    pub fn reference_high(&self) -> f32 {
+        self.ath
+    }
+
+    pub fn reset(&mut self) {
+        self.ath = 0.0f32;
+        self.current_percent_off_ath = 0.0f32;
+        if let Some(rolling_highs) = self.rolling_highs.as_mut() {
+            rolling_highs.clear()
+        }
+    }
+}
+
+impl Indicator for PercentOffAth {
+    type Output = f32;
+
+    /// How far the bar's high is below the highest high, as exported by the data generation.
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high)
+    }
+
+    fn current(&self) -> f32 {
+        PercentOffAth::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        match self.rolling_highs.as_ref() {
+            Some(rolling_highs) => rolling_highs.is_full(),
+            None => self.ath > 0.0
+        }
+    }
+
+    fn reset(&mut self) {
+        PercentOffAth::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_highs.as_ref().map(|rolling_highs| rolling_highs.capacity()).unwrap_or(1)
+    }
+}
+
+impl ValueIndicator for PercentOffAth {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+
+/// Value of a bar an indicator is computed on.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum PriceSource {
+    Open,
+    High,
+    Low,
+    #[default]
+    Close,
+    /// (high + low) / 2
+    Hl2,
+    /// (high + low + close) / 3
+    Hlc3,
+    /// (open + high + low + close) / 4
+    Ohlc4,
+    Volume
+}
+
+impl PriceSource {
+    pub fn value(&self, bar: &StockPriceInfo) -> f32 {
+        match self {
+            PriceSource::Open => bar.open,
+            PriceSource::High => bar.high,
+            PriceSource::Low => bar.low,
+            PriceSource::Close => bar.close,
+            PriceSource::Hl2 => (bar.high + bar.low) / 2.0,
+            PriceSource::Hlc3 => (bar.high + bar.low + bar.close) / 3.0,
+            PriceSource::Ohlc4 => (bar.open + bar.high + bar.low + bar.close) / 4.0,
+            PriceSource::Volume => bar.vol
+        }
+    }
+}
+
+/// Single series indicator computed on `source` instead of the close, e.g.
+/// `Sourced::new(PriceSource::Hlc3, Ema::new(20))`.
+#[derive(Clone)]
+pub struct Sourced<I: ValueIndicator> {
+    source: PriceSource,
+    indicator: I
+}
+
+impl<I: ValueIndicator> Sourced<I> {
+    pub fn new(source: PriceSource, indicator: I) -> Self {
+        Self {
+            source,
+            indicator
+        }
+    }
+}
+
+impl<I: ValueIndicator> Indicator for Sourced<I> {
+    type Output = I::Output;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> I::Output {
+        self.indicator.update_value(self.source.value(bar))
+    }
+
+    fn current(&self) -> I::Output {
+        self.indicator.current()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.indicator.is_ready()
+    }
+
+    fn reset(&mut self) {
+        self.indicator.reset()
+    }
+
+    fn lookback(&self) -> usize {
+        self.indicator.lookback()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::technical_indicator::atr::Atr;
+use crate::technical_indicator::rsi::Rsi;
+
+// Bars built around the closes of the StockCharts RSI example, with a gap down on bar 26 so the
+// true range has to reach back to the previous close. The expected values were computed
+// independently, in double precision, from Wilder's definitions: first average = simple average
+// of the first 14 values, then average = (13 * average + value) / 14.
+const HIGHS: [f32; 33] = [44.60, 44.40, 44.33, 44.16, 44.45, 45.23, 45.32, 45.51, 46.19, 46.36, 46.36, 46.18, 45.94, 46.49, 46.72, 46.19, 46.30, 46.79, 46.33, 46.16, 46.45, 46.55, 45.88, 46.86, 46.14, 45.64, 44.61, 44.32, 44.45, 44.89, 43.91, 43.03, 43.33];
+const LOWS: [f32; 33] = [44.03, 43.87, 43.75, 43.44, 44.05, 44.70, 44.75, 45.16, 45.65, 45.64, 45.65, 45.66, 45.40, 46.12, 45.95, 45.58, 45.85, 46.12, 45.86, 45.39, 46.07, 45.86, 45.44, 46.25, 45.32, 45.04, 43.80, 43.66, 43.88, 44.42, 43.14, 42.25, 42.91];
+const CLOSES: [f32; 33] = [44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03, 45.61, 46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45, 45.78, 45.35, 44.03, 44.18, 44.22, 44.57, 43.42, 42.66, 43.13];
+
+const LENGTH: usize = 14;
+/// 14-day RSI from bar 14, the first one with 14 price changes.
+const RSI_14: [f32; 19] = [70.4641, 66.2496, 66.4809, 69.3469, 66.2947, 57.9150, 62.8807, 63.2088, 56.0116, 62.3399, 54.6710, 50.3868, 40.0194, 41.4926, 41.9024, 45.4995, 37.3228, 33.0905, 37.7888];
+/// 14-day ATR from bar 13; bar 0 has no previous close and contributes its high - low range.
+const ATR_14: [f32; 20] = [0.6679, 0.6752, 0.6769, 0.6607, 0.6678, 0.6594, 0.6716, 0.6815, 0.6821, 0.6912, 0.7240, 0.7530, 0.7521, 0.8091, 0.7984, 0.7821, 0.7741, 0.8209, 0.8459, 0.8333];
+const TOLERANCE: f32 = 0.001;
+
+fn assert_close(indicator: &str, bar: usize, actual: f32, expected: f32) {
+    assert!((actual - expected).abs() <= TOLERANCE.max(expected.abs() * 1e-5),
+            "{} on bar {} is {}, expected {}", indicator, bar, actual, expected);
+}
+
+#[test]
+fn rsi_matches_wilder_reference_values() {
+    let mut rsi = Rsi::new(LENGTH);
+    for bar in 0..CLOSES.len() {
+        let rsi_line = rsi.next(CLOSES[bar]).rsi_line;
+        if bar >= LENGTH {
+            assert_close("RSI", bar, rsi_line, RSI_14[bar - LENGTH]);
+        }
+    }
+}
+
+#[test]
+fn atr_matches_wilder_reference_values() {
+    let mut atr = Atr::new(LENGTH);
+    for bar in 0..CLOSES.len() {
+        let previous_close = if bar > 0 { CLOSES[bar - 1] } else { CLOSES[bar] };
+        let average_true_range = atr.next(HIGHS[bar], LOWS[bar], previous_close);
+        if bar + 1 >= LENGTH {
+            assert_close("ATR", bar, average_true_range, ATR_14[bar + 1 - LENGTH]);
+        }
+    }
+}
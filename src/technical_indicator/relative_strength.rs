@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use crate::stock_data_reader::benchmark::Benchmark;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+use crate::technical_indicator::price_source::PriceSource;
+
+/// Ratio of the ticker's price to the benchmark's on the same date, rising while the ticker
+/// outperforms. Days the benchmark did not trade keep the previous ratio. Chain it to get e.g.
+/// `Chained::new(RelativeStrength::new(benchmark, PriceSource::Close), Macd::default())`.
+#[derive(Clone)]
+pub struct RelativeStrength {
+    benchmark: Arc<Benchmark>,
+    source: PriceSource,
+    ratio: Option<f32>
+}
+
+impl RelativeStrength {
+    pub fn new(benchmark: Arc<Benchmark>, source: PriceSource) -> Self {
+        Self {
+            benchmark,
+            source,
+            ratio: None
+        }
+    }
+}
+
+impl Indicator for RelativeStrength {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        if let Some(benchmark_bar) = self.benchmark.get(bar.date) {
+            let benchmark_value = self.source.value(benchmark_bar);
+            if benchmark_value > 0.0 {
+                self.ratio = Some(self.source.value(bar) / benchmark_value);
+            }
+        }
+        self.current()
+    }
+
+    fn current(&self) -> f32 {
+        self.ratio.unwrap_or(0.0)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ratio.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.ratio = None;
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+}
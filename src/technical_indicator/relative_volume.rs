@@ -0,0 +1,75 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+use crate::technical_indicator::sma::Sma;
+
+/// Today's volume divided by the average volume of the `length` bars before it, so that a
+/// volume spike does not raise its own baseline.
+#[derive(Clone)]
+pub struct RelativeVolume {
+    average_volume: Sma,
+    current_value: f32,
+    is_ready: bool
+}
+
+impl RelativeVolume {
+    pub fn new(length: usize) -> Self {
+        Self {
+            average_volume: Sma::new(length),
+            current_value: 1.0,
+            is_ready: false
+        }
+    }
+
+    pub fn next(&mut self, volume: f32) -> f32 {
+        let average_volume = self.average_volume.current();
+        self.is_ready = self.average_volume.is_ready();
+        self.current_value = if average_volume > 0.0 { volume / average_volume } else { 1.0 };
+        self.average_volume.next(volume);
+        self.current_value
+    }
+
+    pub fn current(&self) -> f32 {
+        self.current_value
+    }
+
+    /// Whether the last value was computed against a full average.
+    pub fn is_ready(&self) -> bool {
+        self.is_ready
+    }
+
+    pub fn reset(&mut self) {
+        self.average_volume.reset();
+        self.current_value = 1.0;
+        self.is_ready = false;
+    }
+}
+
+impl Indicator for RelativeVolume {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        RelativeVolume::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        RelativeVolume::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        RelativeVolume::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.average_volume.lookback() + 1
+    }
+}
+
+impl ValueIndicator for RelativeVolume {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
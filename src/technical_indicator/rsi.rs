@@ -1,10 +1,14 @@
-use ringbuf::LocalRb;
-use ringbuf::storage::Heap;
-use ringbuf::traits::{Consumer, RingBuffer};
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput, ScalarOutput, ValueIndicator};
+use crate::technical_indicator::smoothing::{MovingAverage, Smoothing};
 
+/// RSI = 100 - 100 / (1 + average gain / average loss) over the price changes. Gains and losses
+/// are averaged incrementally, with Wilder's smoothing for the standard RSI.
+#[derive(Clone)]
 pub struct Rsi {
-    last_prices_ring_buffer: LocalRb<Heap<f32>>
-
+    average_gain: MovingAverage,
+    average_loss: MovingAverage,
+    previous_price: Option<f32>
 }
 
 #[derive(Clone)]
@@ -14,25 +18,90 @@ pub struct RsiResult {
 
 impl Rsi {
     pub fn new(length: usize) -> Self {
+        Self::with_smoothing(length, Smoothing::Wilder)
+    }
+
+    /// `Smoothing::Sma` gives Cutler's RSI.
+    pub fn with_smoothing(length: usize, smoothing: Smoothing) -> Self {
         Rsi {
-            last_prices_ring_buffer: LocalRb::<Heap<f32>>::new(length+1)
+            average_gain: MovingAverage::new(smoothing, length),
+            average_loss: MovingAverage::new(smoothing, length),
+            previous_price: None
         }
     }
 
     pub fn next(&mut self, price: f32) -> RsiResult {
-        self.last_prices_ring_buffer.push_overwrite(price);
-        let (losses, gains): (Vec<f32>, Vec<f32>) = self.last_prices_ring_buffer.iter()
-            .zip(self.last_prices_ring_buffer.iter().skip(1))
-            .map(|(&a, &b)| b - a)
-            .partition(|&diff| diff < 0.0f32);
+        if let Some(previous_price) = self.previous_price {
+            let change = price - previous_price;
+            self.average_gain.next(f32::max(change, 0.0));
+            self.average_loss.next(f32::max(-change, 0.0));
+        }
+        self.previous_price = Some(price);
+        self.calculate()
+    }
 
-        let rs = gains.iter().sum::<f32>() / losses.iter().sum::<f32>();
-        let rsi = 100.0 - 100.0/(1.0 + rs);
+    fn calculate(&self) -> RsiResult {
+        let average_gain = self.average_gain.current();
+        let average_loss = self.average_loss.current();
+        let rsi = if average_loss > 0.0 {
+            100.0 - 100.0 / (1.0 + average_gain / average_loss)
+        } else if average_gain > 0.0 {
+            100.0
+        } else {
+            50.0
+        };
 
         RsiResult {
             rsi_line: rsi
         }
+    }
 
+    pub fn reset(&mut self) {
+        self.average_gain.reset();
+        self.average_loss.reset();
+        self.previous_price = None;
     }
 }
 
+impl IndicatorOutput for RsiResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.rsi_line]
+    }
+}
+
+impl ScalarOutput for RsiResult {
+    fn value(&self) -> f32 {
+        self.rsi_line
+    }
+}
+
+impl Indicator for Rsi {
+    type Output = RsiResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> RsiResult {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> RsiResult {
+        self.calculate()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.average_gain.is_ready()
+    }
+
+    fn reset(&mut self) {
+        Rsi::reset(self)
+    }
+
+    /// `length` price changes need one price more.
+    fn lookback(&self) -> usize {
+        self.average_gain.seed_period() + 1
+    }
+}
+
+impl ValueIndicator for Rsi {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
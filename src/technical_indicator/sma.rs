@@ -0,0 +1,80 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Simple moving average of the last `length` prices. The sum is updated with the price entering
+/// and the one leaving the window, so each bar costs O(1).
+#[derive(Clone)]
+pub struct Sma {
+    prices: RollingWindow<f32>,
+    sum: f64
+}
+
+impl Sma {
+    pub fn new(length: usize) -> Self {
+        Self {
+            prices: RollingWindow::new(length),
+            sum: 0.0
+        }
+    }
+
+    pub fn next(&mut self, price: f32) -> f32 {
+        self.sum += price as f64;
+        if let Some(evicted) = self.prices.add(price) {
+            self.sum -= evicted as f64;
+        }
+        self.current()
+    }
+
+    /// Average of the prices seen so far until the window fills up.
+    pub fn current(&self) -> f32 {
+        if self.prices.is_empty() {
+            0.0
+        } else {
+            (self.sum / self.prices.len() as f64) as f32
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.prices.capacity()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.sum = 0.0;
+    }
+}
+
+impl Indicator for Sma {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> f32 {
+        Sma::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Sma::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Sma::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.prices.capacity()
+    }
+}
+
+impl ValueIndicator for Sma {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::technical_indicator::ema::{Ema, EmaSeed};
+use crate::technical_indicator::sma::Sma;
+use crate::technical_indicator::wilder_average::WilderAverage;
+
+/// Averaging used by indicators that smooth a series of their own, e.g. the gains and losses of
+/// `Rsi` or the true ranges of `Atr`.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Smoothing {
+    /// Wilder's running average, alpha = 1 / length. What the standard RSI and ATR use.
+    #[default]
+    Wilder,
+    /// Exponential average, alpha = 2 / (length + 1).
+    Ema,
+    /// Simple average of the last `length` values.
+    Sma
+}
+
+#[derive(Clone)]
+pub enum MovingAverage {
+    Wilder(WilderAverage),
+    Ema(Ema),
+    Sma(Sma)
+}
+
+impl MovingAverage {
+    /// Wilder's average is seeded with a simple average as he defined it, the EMA starts at 0.
+    pub fn new(smoothing: Smoothing, length: usize) -> Self {
+        match smoothing {
+            Smoothing::Wilder => MovingAverage::Wilder(WilderAverage::new(length)),
+            Smoothing::Ema => MovingAverage::Ema(Ema::new(length)),
+            Smoothing::Sma => MovingAverage::Sma(Sma::new(length))
+        }
+    }
+
+    /// `seed` applies to the exponential averages, a simple average needs none.
+    pub fn with_seed(smoothing: Smoothing, length: usize, seed: EmaSeed) -> Self {
+        match smoothing {
+            Smoothing::Wilder => MovingAverage::Wilder(WilderAverage::with_seed(length, seed)),
+            Smoothing::Ema => MovingAverage::Ema(Ema::with_seed(length, seed)),
+            Smoothing::Sma => MovingAverage::Sma(Sma::new(length))
+        }
+    }
+
+    pub fn next(&mut self, value: f32) -> f32 {
+        match self {
+            MovingAverage::Wilder(average) => average.next(value),
+            MovingAverage::Ema(average) => average.next(value),
+            MovingAverage::Sma(average) => average.next(value)
+        }
+    }
+
+    pub fn current(&self) -> f32 {
+        match self {
+            MovingAverage::Wilder(average) => average.current(),
+            MovingAverage::Ema(average) => average.current(),
+            MovingAverage::Sma(average) => average.current()
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        match self {
+            MovingAverage::Wilder(average) => average.is_ready(),
+            MovingAverage::Ema(average) => average.is_ready(),
+            MovingAverage::Sma(average) => average.is_ready()
+        }
+    }
+
+    /// Number of values until the average is ready.
+    pub fn seed_period(&self) -> usize {
+        match self {
+            MovingAverage::Wilder(average) => average.seed_period(),
+            MovingAverage::Ema(average) => average.seed_period(),
+            MovingAverage::Sma(average) => average.length()
+        }
+    }
+
+    pub fn reset(&mut self) {
+        match self {
+            MovingAverage::Wilder(average) => average.reset(),
+            MovingAverage::Ema(average) => average.reset(),
+            MovingAverage::Sma(average) => average.reset()
+        }
+    }
+}
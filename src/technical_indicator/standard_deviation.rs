@@ -0,0 +1,93 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::{Indicator, ValueIndicator};
+use crate::utils::rolling_window::RollingWindow;
+
+/// Population standard deviation of the last `length` prices, as used by Bollinger Bands. Keeps
+/// running sums of the prices and of their squares, so each bar costs O(1).
+#[derive(Clone)]
+pub struct StandardDeviation {
+    prices: RollingWindow<f32>,
+    sum: f64,
+    sum_of_squares: f64
+}
+
+impl StandardDeviation {
+    pub fn new(length: usize) -> Self {
+        Self {
+            prices: RollingWindow::new(length),
+            sum: 0.0,
+            sum_of_squares: 0.0
+        }
+    }
+
+    pub fn next(&mut self, price: f32) -> f32 {
+        let price = price as f64;
+        self.sum += price;
+        self.sum_of_squares += price * price;
+        if let Some(evicted) = self.prices.add(price as f32) {
+            let evicted = evicted as f64;
+            self.sum -= evicted;
+            self.sum_of_squares -= evicted * evicted;
+        }
+        self.current()
+    }
+
+    pub fn mean(&self) -> f32 {
+        if self.prices.is_empty() {
+            0.0
+        } else {
+            (self.sum / self.prices.len() as f64) as f32
+        }
+    }
+
+    pub fn current(&self) -> f32 {
+        if self.prices.is_empty() {
+            return 0.0
+        }
+        let count = self.prices.len() as f64;
+        let mean = self.sum / count;
+        // Cancellation in the running sums can leave a tiny negative variance for flat prices.
+        let variance = f64::max(self.sum_of_squares / count - mean * mean, 0.0);
+        variance.sqrt() as f32
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.prices.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.sum = 0.0;
+        self.sum_of_squares = 0.0;
+    }
+}
+
+impl Indicator for StandardDeviation {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.close)
+    }
+
+    fn current(&self) -> f32 {
+        StandardDeviation::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        StandardDeviation::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        StandardDeviation::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.prices.capacity()
+    }
+}
+
+impl ValueIndicator for StandardDeviation {
+    fn update_value(&mut self, value: f32) -> Self::Output {
+        self.next(value)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::donchian_channel::DonchianChannel;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+use crate::technical_indicator::sma::Sma;
+
+/// Stochastic oscillator: where the close sits within the high-low range of the last `k_length`
+/// bars, 0 at the lowest low and 100 at the highest high. %K is that value smoothed with a
+/// `k_smoothing` SMA and %D is a `d_length` SMA of %K.
+#[derive(Clone)]
+pub struct Stochastic {
+    range: DonchianChannel,
+    k_line: Sma,
+    d_line: Sma,
+    samples: usize
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StochasticResult {
+    pub k: f32,
+    pub d: f32
+}
+
+impl Stochastic {
+    pub fn new(k_length: usize, k_smoothing: usize, d_length: usize) -> Self {
+        Self {
+            range: DonchianChannel::new(k_length),
+            k_line: Sma::new(k_smoothing),
+            d_line: Sma::new(d_length),
+            samples: 0
+        }
+    }
+
+    /// Unsmoothed %K, e.g. `Stochastic::fast(14, 3)`.
+    pub fn fast(k_length: usize, d_length: usize) -> Self {
+        Self::new(k_length, 1, d_length)
+    }
+
+    /// %K smoothed with a 3-bar SMA, i.e. the fast %D, e.g. `Stochastic::slow(14, 3)`.
+    pub fn slow(k_length: usize, d_length: usize) -> Self {
+        Self::new(k_length, 3, d_length)
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> StochasticResult {
+        let range = self.range.next(high, low);
+        let width = range.upper_band - range.lower_band;
+        let raw_k = if width > 0.0 {
+            100.0 * (close - range.lower_band) / width
+        } else {
+            50.0
+        };
+        let k = self.k_line.next(raw_k);
+        self.samples += 1;
+        StochasticResult {
+            k,
+            d: self.d_line.next(k)
+        }
+    }
+
+    pub fn current(&self) -> StochasticResult {
+        StochasticResult {
+            k: self.k_line.current(),
+            d: self.d_line.current()
+        }
+    }
+
+    /// %D needs `k_length + k_smoothing + d_length - 2` bars.
+    pub fn is_ready(&self) -> bool {
+        self.samples >= Indicator::lookback(self)
+    }
+
+    pub fn reset(&mut self) {
+        self.range.reset();
+        self.k_line.reset();
+        self.d_line.reset();
+        self.samples = 0;
+    }
+}
+
+impl IndicatorOutput for StochasticResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.k, self.d]
+    }
+}
+
+impl Indicator for Stochastic {
+    type Output = StochasticResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> StochasticResult {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> StochasticResult {
+        Stochastic::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Stochastic::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Stochastic::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.range.lookback() + self.k_line.lookback() + self.d_line.lookback() - 2
+    }
+}
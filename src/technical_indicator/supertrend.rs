@@ -0,0 +1,121 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::atr::Atr;
+use crate::technical_indicator::indicator::{Indicator, IndicatorOutput};
+
+/// Supertrend: bands `multiplier` ATRs around the bar's midpoint (high + low) / 2 that only
+/// ever tighten while the trend lasts. The lower band is the trailing stop of an uptrend, the
+/// upper band that of a downtrend, and a close beyond the active band flips the trend.
+#[derive(Clone)]
+pub struct Supertrend {
+    atr: Atr,
+    multiplier: f32,
+    upper_band: f32,
+    lower_band: f32,
+    is_uptrend: bool,
+    previous_close: Option<f32>
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SupertrendResult {
+    /// Lower band in an uptrend, upper band in a downtrend.
+    pub supertrend: f32,
+    pub is_uptrend: bool,
+    /// Whether the last close flipped the trend.
+    pub flipped: bool
+}
+
+impl Supertrend {
+    pub fn new(atr_length: usize, multiplier: f32) -> Self {
+        Self {
+            atr: Atr::new(atr_length),
+            multiplier,
+            upper_band: 0.0,
+            lower_band: 0.0,
+            is_uptrend: true,
+            previous_close: None
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> SupertrendResult {
+        let atr = self.atr.next(high, low, self.previous_close.unwrap_or(close));
+        let middle = (high + low) / 2.0;
+        let basic_upper_band = middle + self.multiplier * atr;
+        let basic_lower_band = middle - self.multiplier * atr;
+
+        let mut flipped = false;
+        match self.previous_close {
+            Some(previous_close) => {
+                if basic_upper_band < self.upper_band || previous_close > self.upper_band {
+                    self.upper_band = basic_upper_band;
+                }
+                if basic_lower_band > self.lower_band || previous_close < self.lower_band {
+                    self.lower_band = basic_lower_band;
+                }
+                let is_uptrend = if self.is_uptrend { close >= self.lower_band } else { close > self.upper_band };
+                flipped = is_uptrend != self.is_uptrend;
+                self.is_uptrend = is_uptrend;
+            }
+            None => {
+                self.upper_band = basic_upper_band;
+                self.lower_band = basic_lower_band;
+                self.is_uptrend = true;
+            }
+        }
+        self.previous_close = Some(close);
+
+        SupertrendResult {
+            flipped,
+            ..self.current()
+        }
+    }
+
+    pub fn current(&self) -> SupertrendResult {
+        SupertrendResult {
+            supertrend: if self.is_uptrend { self.lower_band } else { self.upper_band },
+            is_uptrend: self.is_uptrend,
+            flipped: false
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        Indicator::is_ready(&self.atr)
+    }
+
+    pub fn reset(&mut self) {
+        self.atr.reset();
+        self.upper_band = 0.0;
+        self.lower_band = 0.0;
+        self.is_uptrend = true;
+        self.previous_close = None;
+    }
+}
+
+impl IndicatorOutput for SupertrendResult {
+    fn to_values(&self) -> Vec<f32> {
+        vec![self.supertrend, if self.is_uptrend { 1.0 } else { -1.0 }]
+    }
+}
+
+impl Indicator for Supertrend {
+    type Output = SupertrendResult;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> SupertrendResult {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> SupertrendResult {
+        Supertrend::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        Supertrend::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        Supertrend::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+}
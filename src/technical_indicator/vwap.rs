@@ -0,0 +1,149 @@
+use chrono::NaiveDate;
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::indicator::Indicator;
+use crate::utils::rolling_window::RollingWindow;
+
+fn typical_price(high: f32, low: f32, close: f32) -> f32 {
+    (high + low + close) / 3.0
+}
+
+/// Volume weighted average of the typical price (high + low + close) / 3 over the last `length`
+/// daily bars.
+#[derive(Clone)]
+pub struct RollingVwap {
+    bars: RollingWindow<(f32, f32)>,
+    price_volume: f64,
+    volume: f64
+}
+
+impl RollingVwap {
+    pub fn new(length: usize) -> Self {
+        Self {
+            bars: RollingWindow::new(length),
+            price_volume: 0.0,
+            volume: 0.0
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32, volume: f32) -> f32 {
+        let price_volume = typical_price(high, low, close) * volume;
+        self.price_volume += price_volume as f64;
+        self.volume += volume as f64;
+        if let Some((evicted_price_volume, evicted_volume)) = self.bars.add((price_volume, volume)) {
+            self.price_volume -= evicted_price_volume as f64;
+            self.volume -= evicted_volume as f64;
+        }
+        self.current()
+    }
+
+    pub fn current(&self) -> f32 {
+        if self.volume > 0.0 {
+            (self.price_volume / self.volume) as f32
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.bars.is_full()
+    }
+
+    pub fn reset(&mut self) {
+        self.bars.clear();
+        self.price_volume = 0.0;
+        self.volume = 0.0;
+    }
+}
+
+impl Indicator for RollingVwap {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        RollingVwap::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        RollingVwap::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        RollingVwap::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.bars.capacity()
+    }
+}
+
+/// VWAP of every bar since `anchor_date`, e.g. an earnings release or a major high. Bars before
+/// the anchor are ignored and the value stays 0 until the anchor is reached.
+#[derive(Clone)]
+pub struct AnchoredVwap {
+    anchor_date: NaiveDate,
+    price_volume: f64,
+    volume: f64
+}
+
+impl AnchoredVwap {
+    pub fn new(anchor_date: NaiveDate) -> Self {
+        Self {
+            anchor_date,
+            price_volume: 0.0,
+            volume: 0.0
+        }
+    }
+
+    pub fn next(&mut self, date: NaiveDate, high: f32, low: f32, close: f32, volume: f32) -> f32 {
+        if date >= self.anchor_date {
+            self.price_volume += (typical_price(high, low, close) * volume) as f64;
+            self.volume += volume as f64;
+        }
+        self.current()
+    }
+
+    pub fn current(&self) -> f32 {
+        if self.volume > 0.0 {
+            (self.price_volume / self.volume) as f32
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.volume > 0.0
+    }
+
+    pub fn reset(&mut self) {
+        self.price_volume = 0.0;
+        self.volume = 0.0;
+    }
+}
+
+impl Indicator for AnchoredVwap {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.date, bar.high, bar.low, bar.close, bar.vol)
+    }
+
+    fn current(&self) -> f32 {
+        AnchoredVwap::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        AnchoredVwap::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        AnchoredVwap::reset(self)
+    }
+
+    /// Depends on the anchor date rather than on a number of bars.
+    fn lookback(&self) -> usize {
+        1
+    }
+}
@@ -0,0 +1,62 @@
+use crate::technical_indicator::ema::EmaSeed;
+
+/// Wilder's smoothing: `average += (value - average) / length`, i.e. an EMA with
+/// alpha = 1 / length. Wilder seeded it with the simple average of the first `length` values.
+#[derive(Clone)]
+pub struct WilderAverage {
+    length: usize,
+    average: f32,
+    samples: usize,
+    seed: EmaSeed
+}
+
+impl WilderAverage {
+    pub fn new(length: usize) -> Self {
+        Self::with_seed(length, EmaSeed::Sma)
+    }
+
+    pub fn with_seed(length: usize, seed: EmaSeed) -> Self {
+        Self {
+            length,
+            average: 0.0,
+            samples: 0,
+            seed
+        }
+    }
+
+    pub fn next(&mut self, value: f32) -> f32 {
+        let divisor = match self.seed {
+            EmaSeed::FirstValue if self.samples == 0 => 1,
+            EmaSeed::Sma => usize::min(self.samples + 1, self.length),
+            _ => self.length
+        };
+        self.average += (value - self.average) / divisor as f32;
+        self.samples += 1;
+        self.average
+    }
+
+    pub fn current(&self) -> f32 {
+        self.average
+    }
+
+    /// Number of values until the seed is complete: one for `FirstValue`, `length` otherwise.
+    pub fn seed_period(&self) -> usize {
+        match self.seed {
+            EmaSeed::FirstValue => 1,
+            EmaSeed::Sma | EmaSeed::Zero => self.length
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.samples >= self.seed_period()
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn reset(&mut self) {
+        self.average = 0.0;
+        self.samples = 0;
+    }
+}
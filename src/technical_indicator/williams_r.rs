@@ -0,0 +1,68 @@
+use crate::stock_data_reader::stock_data_reader::StockPriceInfo;
+use crate::technical_indicator::donchian_channel::DonchianChannel;
+use crate::technical_indicator::indicator::Indicator;
+
+/// Williams %R: distance of the close below the highest high of the last `length` bars relative
+/// to their range, from 0 at the highest high down to -100 at the lowest low.
+#[derive(Clone)]
+pub struct WilliamsR {
+    range: DonchianChannel,
+    current_value: f32
+}
+
+impl WilliamsR {
+    pub fn new(length: usize) -> Self {
+        Self {
+            range: DonchianChannel::new(length),
+            current_value: -50.0
+        }
+    }
+
+    pub fn next(&mut self, high: f32, low: f32, close: f32) -> f32 {
+        let range = self.range.next(high, low);
+        let width = range.upper_band - range.lower_band;
+        self.current_value = if width > 0.0 {
+            -100.0 * (range.upper_band - close) / width
+        } else {
+            -50.0
+        };
+        self.current_value
+    }
+
+    pub fn current(&self) -> f32 {
+        self.current_value
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.range.is_ready()
+    }
+
+    pub fn reset(&mut self) {
+        self.range.reset();
+        self.current_value = -50.0;
+    }
+}
+
+impl Indicator for WilliamsR {
+    type Output = f32;
+
+    fn update(&mut self, bar: &StockPriceInfo) -> f32 {
+        self.next(bar.high, bar.low, bar.close)
+    }
+
+    fn current(&self) -> f32 {
+        WilliamsR::current(self)
+    }
+
+    fn is_ready(&self) -> bool {
+        WilliamsR::is_ready(self)
+    }
+
+    fn reset(&mut self) {
+        WilliamsR::reset(self)
+    }
+
+    fn lookback(&self) -> usize {
+        self.range.lookback()
+    }
+}
@@ -1,2 +1,3 @@
 pub mod vec_to_csv;
-pub mod rolling_window;
\ No newline at end of file
+pub mod rolling_window;
+pub mod trading_calendar;
\ No newline at end of file
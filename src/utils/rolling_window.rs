@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 
+#[derive(Clone)]
 pub struct RollingWindow<T> {
     window: VecDeque<T>,
     max_size: usize
@@ -13,11 +14,15 @@ impl<T> RollingWindow<T> {
         }
     }
 
-    pub fn add(&mut self, value: T) {
-        if self.window.len() == self.max_size {
-            self.window.pop_front();
-        }
-        self.window.push_back(value)
+    /// Appends `value` and returns the oldest one when the window was already full.
+    pub fn add(&mut self, value: T) -> Option<T> {
+        let evicted = if self.window.len() == self.max_size {
+            self.window.pop_front()
+        } else {
+            None
+        };
+        self.window.push_back(value);
+        evicted
     }
 
    pub fn as_slice(&self) -> &[T] {
@@ -28,4 +33,28 @@ impl<T> RollingWindow<T> {
         self.window.get(n)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.window.iter()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.max_size
+    }
+
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.max_size
+    }
+
+    pub fn clear(&mut self) {
+        self.window.clear()
+    }
+
 }
\ No newline at end of file
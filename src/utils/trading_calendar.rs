@@ -0,0 +1,125 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// NYSE trading days: weekdays that are not exchange holidays. Holidays are derived from the
+/// exchange rules, so the calendar can be looked ahead without peeking at price data.
+pub struct TradingCalendar;
+
+impl TradingCalendar {
+    pub fn is_trading_day(date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !Self::is_holiday(date)
+    }
+
+    pub fn next_trading_day(date: NaiveDate) -> NaiveDate {
+        let mut next = date + Days::new(1);
+        while !Self::is_trading_day(next) {
+            next = next + Days::new(1);
+        }
+        next
+    }
+
+    pub fn previous_trading_day(date: NaiveDate) -> NaiveDate {
+        let mut previous = date - Days::new(1);
+        while !Self::is_trading_day(previous) {
+            previous = previous - Days::new(1);
+        }
+        previous
+    }
+
+    /// 1 for the first trading day of the month.
+    pub fn trading_day_of_month(date: NaiveDate) -> u32 {
+        let mut day = date.with_day(1).unwrap();
+        let mut trading_days = 0;
+        while day <= date {
+            if Self::is_trading_day(day) {
+                trading_days += 1;
+            }
+            day = day + Days::new(1);
+        }
+        trading_days
+    }
+
+    /// 1 for the last trading day of the month.
+    pub fn trading_days_to_month_end(date: NaiveDate) -> u32 {
+        let mut day = date;
+        let mut trading_days = 0;
+        while day.month() == date.month() {
+            if Self::is_trading_day(day) {
+                trading_days += 1;
+            }
+            day = day + Days::new(1);
+        }
+        trading_days
+    }
+
+    /// Number of trading days from `date` up to the next weekday the exchange is closed for a
+    /// holiday, 1 meaning `date` is the last session before it.
+    pub fn trading_days_to_holiday(date: NaiveDate, max_days: u32) -> Option<u32> {
+        let mut day = date;
+        let mut trading_days = 0;
+        while trading_days <= max_days {
+            if Self::is_trading_day(day) {
+                trading_days += 1;
+            } else if Self::is_holiday(day) {
+                return Some(trading_days)
+            }
+            day = day + Days::new(1);
+        }
+        None
+    }
+
+    pub fn is_holiday(date: NaiveDate) -> bool {
+        let year = date.year();
+        let holidays = [
+            observed(NaiveDate::from_ymd_opt(year, 1, 1).unwrap()),
+            nth_weekday(year, 1, Weekday::Mon, 3),
+            nth_weekday(year, 2, Weekday::Mon, 3),
+            easter_sunday(year) - Days::new(2),
+            last_weekday(year, 5, Weekday::Mon),
+            observed(NaiveDate::from_ymd_opt(year, 7, 4).unwrap()),
+            nth_weekday(year, 9, Weekday::Mon, 1),
+            nth_weekday(year, 11, Weekday::Thu, 4),
+            observed(NaiveDate::from_ymd_opt(year, 12, 25).unwrap())
+        ];
+        let juneteenth = observed(NaiveDate::from_ymd_opt(year, 6, 19).unwrap());
+        holidays.contains(&date) || (year >= 2022 && date == juneteenth)
+    }
+}
+
+// Saturday holidays are observed on Friday and Sunday holidays on Monday, except New Year's Day
+// falling on Saturday, which the exchange does not observe on the previous Friday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat if date.month() == 1 && date.day() == 1 => date,
+        Weekday::Sat => date - Days::new(1),
+        Weekday::Sun => date + Days::new(1),
+        _ => date
+    }
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n).unwrap()
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+// Anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
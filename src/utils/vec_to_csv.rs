@@ -2,6 +2,7 @@ use std::error::Error;
 use std::fs::File;
 use chrono::NaiveDate;
 use csv::Writer;
+use crate::results_statistics::seasonality::SeasonalityBucket;
 
 pub trait SaveVecToCsv {
     fn save_to_csv(&self, file_path: &str) -> Result<(), Box<dyn Error>>;
@@ -60,4 +61,17 @@ impl SaveVecToCsv for Vec<(Vec<f32>, f32)> {
         wtr.flush()?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+impl SaveVecToCsv for Vec<SeasonalityBucket> {
+    fn save_to_csv(&self, file_path: &str) -> Result<(), Box<dyn Error>> {
+        let file = File::create(file_path)?;
+        let mut wtr = Writer::from_writer(file);
+
+        for bucket in self {
+            wtr.serialize(bucket)?;
+        }
+
+        wtr.flush()?;
+        Ok(())
+    }
+}